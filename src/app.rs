@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind,
+        KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
@@ -21,18 +24,50 @@ use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use ratatui::{
     Terminal,
     backend::{Backend, CrosstermBackend},
-    text::Text,
+    text::{Line, Text},
+};
+use regex::{Regex, RegexBuilder};
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Duration,
 };
-use std::{io, path::PathBuf, sync::mpsc, time::Duration};
 
 use crate::error::{MdViewError, Result};
-use crate::markdown::MarkdownRenderer;
+use crate::keybindings::{Action, KeyBindings};
+use crate::markdown::{ColorCapability, DEFAULT_RENDER_WIDTH, Heading, LinkTarget, MarkdownRenderer};
+use crate::position::{self, SavedPosition};
+use crate::theme::{self, UiTheme};
 use crate::ui;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AppMode {
     Normal,
     Search,
+    /// Waiting for the letter to record the current scroll offset under.
+    Mark,
+    /// Waiting for the letter to jump the scroll offset back to.
+    Jump,
+    /// Browsing the document outline, selecting a heading to jump to.
+    Outline,
+    /// Showing the reading-progress and document metadata overlay.
+    Info,
+    /// Cycling through on-screen links, ready to follow the selected one.
+    Link,
+    /// Browsing the built-in UI themes, previewing each as the selection moves.
+    ThemePicker,
+}
+
+/// Reading-progress snapshot shown in the info panel: how far into the document the reader is,
+/// how many screens that spans, and which section they're currently in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metadata {
+    pub progress_percent: f32,
+    pub current_screen: u16,
+    pub total_screens: u16,
+    pub current_section: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -41,14 +76,22 @@ pub struct SearchState {
     pub results: Vec<SearchResult>,
     pub current_result_index: Option<usize>,
     pub is_active: bool,
+    /// Match `query` as a regular expression instead of a literal substring.
+    pub regex_mode: bool,
+    /// Match `query` with case sensitivity instead of lowercasing both sides.
+    pub case_sensitive: bool,
+    /// Only keep matches bordered by non-word characters (or the start/end of the line) on both
+    /// sides, same as `\b` in a regex.
+    pub whole_word: bool,
+    /// Set when `query` doesn't compile as a regex in `regex_mode`, so the search bar can say so
+    /// instead of silently returning no results.
+    pub invalid_pattern: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub line_index: usize,
-    #[allow(dead_code)]
     pub char_start: usize,
-    #[allow(dead_code)]
     pub char_end: usize,
 }
 
@@ -56,49 +99,158 @@ pub struct App {
     file_path: PathBuf,
     renderer: MarkdownRenderer,
     rendered_content: Text<'static>,
+    render_width: u16,
+    /// Number of content rows visible in the terminal, kept in sync from `ui::draw` so
+    /// screen-count math (the info panel, visible-link cycling) reflects the real viewport.
+    viewport_height: u16,
+    /// Absolute terminal row of the content pane's top border, kept in sync from `ui::draw` so
+    /// mouse clicks can be translated into a line within the document.
+    content_area_top: u16,
     scroll_offset: u16,
     content_length: u16,
     watching: bool,
+    /// Whether the last read position is restored on startup and saved on quit. Disabled with
+    /// `--no-resume`.
+    resume_enabled: bool,
     should_quit: bool,
     mode: AppMode,
+    /// Resolves a pressed key to the normal-mode `Action` it triggers, built from defaults and
+    /// any user overrides loaded at startup.
+    key_bindings: KeyBindings,
+    /// Active UI chrome theme, distinct from the syntax highlighting theme applied to code
+    /// blocks.
+    ui_theme: UiTheme,
+    /// Selected row while browsing `AppMode::ThemePicker`.
+    ui_theme_picker_index: usize,
+    /// Theme active before entering the picker, restored if the user cancels with Esc.
+    ui_theme_before_picker: UiTheme,
     search_state: SearchState,
+    /// Vim-style marks: letter -> saved scroll offset, session-only.
+    marks: HashMap<char, u16>,
+    /// Headings parsed from the document, for the outline navigation overlay.
+    outline: Vec<Heading>,
+    /// Currently highlighted entry in the outline overlay.
+    outline_index: usize,
+    /// Whether the left-hand outline sidebar is shown alongside the content, toggled by
+    /// `Action::ToggleOutlinePane`. Off by default so existing users keep today's full-width
+    /// reading view.
+    outline_pane_visible: bool,
+    /// Link targets parsed from the document, for link-following navigation.
+    links: Vec<LinkTarget>,
+    /// Currently selected link while cycling through them in `AppMode::Link`.
+    link_index: usize,
+    /// Files (and the scroll offset within them) visited before following a link, popped by the
+    /// back key to retrace the navigation.
+    history: Vec<(PathBuf, u16)>,
     #[allow(dead_code)]
     file_watcher: Option<RecommendedWatcher>,
     file_change_rx: Option<mpsc::Receiver<()>>,
+    /// Last full file content incorporated into `rendered_content`, so watch-mode changes can be
+    /// diffed down to just the appended suffix instead of re-rendering everything.
+    streamed_content: String,
+    /// Text appended since the last flushed line, waiting for a safe split point.
+    stream_buffer: String,
+    /// Whether the streaming scan is currently inside a fenced code block, which (along with
+    /// headings/blockquotes/tables) needs multi-line context a single stateless line can't give.
+    stream_in_code_block: bool,
 }
 
 impl App {
-    pub fn new(file_path: PathBuf, watch: bool) -> Result<Self> {
-        let mut renderer = MarkdownRenderer::new();
+    pub fn new(
+        file_path: PathBuf,
+        watch: bool,
+        color_capability: ColorCapability,
+        theme_name: &str,
+        line_numbers: bool,
+        resume_enabled: bool,
+        color_overrides: &[(String, String)],
+    ) -> Result<Self> {
+        let mut renderer = MarkdownRenderer::new(color_capability, theme_name, line_numbers);
         renderer.load_file(&file_path)?;
-        let rendered_content = renderer.render_to_text();
+        let render_width = DEFAULT_RENDER_WIDTH as u16;
+        let rendered_content = renderer.render_to_text(render_width as usize);
         let content_length = rendered_content.lines.len() as u16;
+        let streamed_content = renderer.content().to_string();
+        let outline = renderer.headings(render_width as usize);
+        let links = renderer.links(render_width as usize);
 
         let (file_watcher, file_change_rx) = if watch {
-            let (tx, rx) = mpsc::channel();
-            let mut watcher = notify::recommended_watcher(move |_| {
-                let _ = tx.send(());
-            })?;
-
-            watcher.watch(&file_path, RecursiveMode::NonRecursive)?;
+            let (watcher, rx) = Self::watch_file(&file_path)?;
             (Some(watcher), Some(rx))
         } else {
             (None, None)
         };
 
-        Ok(Self {
+        let saved_position = resume_enabled.then(|| position::load(&file_path)).flatten();
+        let scroll_offset = saved_position
+            .as_ref()
+            .map(|saved| saved.scroll_offset)
+            .filter(|&offset| offset < content_length)
+            .unwrap_or(0);
+        let mut search_state = SearchState::default();
+        if let Some(saved) = saved_position {
+            search_state.query = saved.query;
+        }
+
+        let mut ui_theme = theme::load_selected().unwrap_or(theme::DARK);
+        for (field, value) in theme::load_color_overrides()
+            .iter()
+            .chain(color_overrides)
+        {
+            if let Err(err) = theme::apply_color_override(&mut ui_theme, field, value) {
+                eprintln!("Ignoring invalid color override '{}': {}", field, err);
+            }
+        }
+
+        let mut app = Self {
             file_path,
             renderer,
             rendered_content,
-            scroll_offset: 0,
+            render_width,
+            viewport_height: 20,
+            content_area_top: 0,
+            scroll_offset,
             content_length,
             watching: watch,
+            resume_enabled,
             should_quit: false,
             mode: AppMode::Normal,
-            search_state: SearchState::default(),
+            key_bindings: KeyBindings::load(),
+            ui_theme,
+            ui_theme_picker_index: 0,
+            ui_theme_before_picker: theme::DARK,
+            search_state,
+            marks: HashMap::new(),
+            outline,
+            outline_index: 0,
+            outline_pane_visible: false,
+            links,
+            link_index: 0,
+            history: Vec::new(),
             file_watcher,
             file_change_rx,
-        })
+            streamed_content,
+            stream_buffer: String::new(),
+            stream_in_code_block: false,
+        };
+
+        if !app.search_state.query.is_empty() {
+            app.search_state.is_active = true;
+            app.recompute_search_results();
+        }
+
+        Ok(app)
+    }
+
+    /// Start watching `path` for changes, used both at startup and after following a link to a
+    /// different file so `--watch` keeps tracking whichever document is open.
+    fn watch_file(path: &PathBuf) -> Result<(RecommendedWatcher, mpsc::Receiver<()>)> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |_| {
+            let _ = tx.send(());
+        })?;
+        watcher.watch(path, RecursiveMode::NonRecursive)?;
+        Ok((watcher, rx))
     }
 
     pub fn run(&mut self) -> Result<()> {
@@ -113,6 +265,10 @@ impl App {
 
         let result = self.run_app(&mut terminal);
 
+        if self.resume_enabled {
+            self.save_position();
+        }
+
         // Restore terminal
         disable_raw_mode().map_err(|e| MdViewError::Terminal(e.to_string()))?;
         execute!(
@@ -142,120 +298,159 @@ impl App {
             if let Some(ref rx) = self.file_change_rx
                 && rx.try_recv().is_ok()
             {
-                self.reload_file()?;
+                self.handle_file_change()?;
             }
 
             // Handle input events
             if event::poll(Duration::from_millis(100))
                 .map_err(|e| MdViewError::Terminal(e.to_string()))?
-                && let Event::Key(key) =
-                    event::read().map_err(|e| MdViewError::Terminal(e.to_string()))?
-                && key.kind == KeyEventKind::Press
             {
-                self.handle_key_event(key.code);
+                match event::read().map_err(|e| MdViewError::Terminal(e.to_string()))? {
+                    Event::Key(key) if key.kind == KeyEventKind::Press => {
+                        self.handle_key_event(key.code, key.modifiers);
+                    }
+                    Event::Mouse(mouse_event) => {
+                        if let Err(e) = self.handle_mouse_event(mouse_event) {
+                            eprintln!("Failed to follow link: {}", e);
+                        }
+                    }
+                    _ => {}
+                }
             }
         }
 
         Ok(())
     }
 
-    fn handle_key_event(&mut self, key_code: KeyCode) {
+    fn handle_key_event(&mut self, key_code: KeyCode, modifiers: KeyModifiers) {
         match self.mode {
             AppMode::Normal => self.handle_normal_mode_key(key_code),
-            AppMode::Search => self.handle_search_mode_key(key_code),
+            AppMode::Search => self.handle_search_mode_key(key_code, modifiers),
+            AppMode::Mark => self.handle_mark_mode_key(key_code),
+            AppMode::Jump => self.handle_jump_mode_key(key_code),
+            AppMode::Outline => self.handle_outline_mode_key(key_code),
+            AppMode::Info => self.handle_info_mode_key(key_code),
+            AppMode::Link => self.handle_link_mode_key(key_code),
+            AppMode::ThemePicker => self.handle_theme_picker_mode_key(key_code),
         }
     }
 
     fn handle_normal_mode_key(&mut self, key_code: KeyCode) {
-        match key_code {
-            // Quit
-            KeyCode::Char('q') => {
+        let Some(action) = self.key_bindings.action_for(key_code) else {
+            return;
+        };
+
+        match action {
+            Action::Quit => {
                 self.should_quit = true;
             }
-            // Start search
-            KeyCode::Char('/') => {
+            Action::StartSearch => {
                 self.start_search();
             }
-            // Next search result
-            KeyCode::Char('n') => {
+            Action::Mark => {
+                self.mode = AppMode::Mark;
+            }
+            Action::Jump => {
+                self.mode = AppMode::Jump;
+            }
+            Action::Outline => {
+                self.start_outline();
+            }
+            Action::ToggleOutlinePane => {
+                self.outline_pane_visible = !self.outline_pane_visible;
+            }
+            Action::Info => {
+                self.mode = AppMode::Info;
+            }
+            Action::NextResult => {
                 self.next_search_result();
             }
-            // Previous search result
-            KeyCode::Char('N') => {
+            Action::PreviousResult => {
                 self.previous_search_result();
             }
-            // Reload file
-            KeyCode::Char('r') => {
+            Action::Reload => {
                 if let Err(e) = self.reload_file() {
                     eprintln!("Failed to reload file: {}", e);
                 }
             }
-            // Vim-style movement: up
-            KeyCode::Up | KeyCode::Char('k') => {
+            Action::LinkCycle => {
+                self.start_link_cycle();
+            }
+            Action::NavigateBack => {
+                if let Err(e) = self.navigate_back() {
+                    eprintln!("Failed to go back: {}", e);
+                }
+            }
+            Action::ThemePicker => {
+                self.start_theme_picker();
+            }
+            Action::ScrollUp => {
                 self.scroll_up();
             }
-            // Vim-style movement: down
-            KeyCode::Down | KeyCode::Char('j') => {
+            Action::ScrollDown => {
                 self.scroll_down();
             }
-            // Vim-style movement: half page up
-            KeyCode::Char('u') => {
+            Action::HalfPageUp => {
                 self.scroll_half_page_up();
             }
-            // Vim-style movement: half page down
-            KeyCode::Char('d') => {
+            Action::HalfPageDown => {
                 self.scroll_half_page_down();
             }
-            // Vim-style movement: full page up
-            KeyCode::PageUp | KeyCode::Char('b') => {
+            Action::PageUp => {
                 self.scroll_page_up();
             }
-            // Vim-style movement: full page down
-            KeyCode::PageDown | KeyCode::Char('f') => {
+            Action::PageDown => {
                 self.scroll_page_down();
             }
-            // Vim-style movement: top of document
-            KeyCode::Home | KeyCode::Char('g') => {
+            Action::GoToTop => {
                 self.scroll_to_top();
             }
-            // Vim-style movement: bottom of document
-            KeyCode::End | KeyCode::Char('G') => {
+            Action::GoToBottom => {
                 self.scroll_to_bottom();
             }
-            // Vim-style movement: move up 5 lines
-            KeyCode::Char('K') => {
+            Action::ScrollUpFive => {
                 for _ in 0..5 {
                     self.scroll_up();
                 }
             }
-            // Vim-style movement: move down 5 lines
-            KeyCode::Char('J') => {
+            Action::ScrollDownFive => {
                 for _ in 0..5 {
                     self.scroll_down();
                 }
             }
-            // Vim-style movement: move to middle of screen
-            KeyCode::Char('M') => {
+            Action::GoToMiddle => {
                 self.scroll_to_middle();
             }
-            // Vim-style movement: move up 10 lines (alternative to page up)
-            KeyCode::Char('U') => {
+            Action::ScrollUpTen => {
                 for _ in 0..10 {
                     self.scroll_up();
                 }
             }
-            // Vim-style movement: move down 10 lines (alternative to page down)
-            KeyCode::Char('D') => {
+            Action::ScrollDownTen => {
                 for _ in 0..10 {
                     self.scroll_down();
                 }
             }
-            _ => {}
         }
     }
 
-    fn handle_search_mode_key(&mut self, key_code: KeyCode) {
+    fn handle_search_mode_key(&mut self, key_code: KeyCode, modifiers: KeyModifiers) {
         match key_code {
+            // Toggle regex matching on/off
+            KeyCode::Char('r') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.search_state.regex_mode = !self.search_state.regex_mode;
+                self.perform_search();
+            }
+            // Toggle case-sensitive matching on/off
+            KeyCode::Char('t') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.search_state.case_sensitive = !self.search_state.case_sensitive;
+                self.perform_search();
+            }
+            // Toggle whole-word matching on/off
+            KeyCode::Char('w') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.search_state.whole_word = !self.search_state.whole_word;
+                self.perform_search();
+            }
             KeyCode::Char(c) => {
                 self.search_state.query.push(c);
                 self.perform_search();
@@ -278,6 +473,227 @@ impl App {
         }
     }
 
+    fn handle_mark_mode_key(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char(c) => {
+                self.marks.insert(c, self.scroll_offset);
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_jump_mode_key(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char(c) => {
+                if let Some(&offset) = self.marks.get(&c) {
+                    self.scroll_offset = offset;
+                }
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    fn start_outline(&mut self) {
+        if self.outline.is_empty() {
+            return;
+        }
+        self.mode = AppMode::Outline;
+        self.outline_index = 0;
+    }
+
+    fn handle_outline_mode_key(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.outline_index = self.outline_index.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.outline_index + 1 < self.outline.len() {
+                    self.outline_index += 1;
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(heading) = self.outline.get(self.outline_index) {
+                    self.scroll_offset = heading.line_index as u16;
+                }
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_info_mode_key(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Char('i') | KeyCode::Esc | KeyCode::Enter => {
+                self.mode = AppMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    fn start_theme_picker(&mut self) {
+        self.mode = AppMode::ThemePicker;
+        self.ui_theme_before_picker = self.ui_theme;
+        self.ui_theme_picker_index = theme::ALL
+            .iter()
+            .position(|candidate| candidate.name == self.ui_theme.name)
+            .unwrap_or(0);
+    }
+
+    /// Move the selection (previewing the theme live) or commit/cancel the pick.
+    fn handle_theme_picker_mode_key(&mut self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.ui_theme_picker_index = self.ui_theme_picker_index.saturating_sub(1);
+                self.ui_theme = theme::ALL[self.ui_theme_picker_index];
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.ui_theme_picker_index + 1 < theme::ALL.len() {
+                    self.ui_theme_picker_index += 1;
+                }
+                self.ui_theme = theme::ALL[self.ui_theme_picker_index];
+            }
+            KeyCode::Enter => {
+                theme::save_selected(&self.ui_theme);
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Esc => {
+                self.ui_theme = self.ui_theme_before_picker;
+                self.mode = AppMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    /// Links whose line falls within the currently visible viewport.
+    fn visible_links(&self) -> Vec<usize> {
+        let first_visible = self.scroll_offset;
+        let last_visible = self
+            .scroll_offset
+            .saturating_add(self.viewport_height.saturating_sub(1));
+
+        self.links
+            .iter()
+            .enumerate()
+            .filter(|(_, link)| {
+                let line = link.line_index as u16;
+                line >= first_visible && line <= last_visible
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn start_link_cycle(&mut self) {
+        let visible = self.visible_links();
+        if visible.is_empty() {
+            return;
+        }
+        self.mode = AppMode::Link;
+        self.link_index = visible[0];
+    }
+
+    fn handle_link_mode_key(&mut self, key_code: KeyCode) {
+        let visible = self.visible_links();
+        if visible.is_empty() {
+            self.mode = AppMode::Normal;
+            return;
+        }
+        let current_position = visible
+            .iter()
+            .position(|&index| index == self.link_index)
+            .unwrap_or(0);
+
+        match key_code {
+            KeyCode::Tab | KeyCode::Char('l') => {
+                let next_position = (current_position + 1) % visible.len();
+                self.link_index = visible[next_position];
+            }
+            KeyCode::Enter => {
+                if let Some(link) = self.links.get(self.link_index).cloned()
+                    && let Err(e) = self.open_link(&link.url)
+                {
+                    eprintln!("Failed to open link: {}", e);
+                }
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    /// Follow `url` if it resolves to an existing relative Markdown file: push the current file
+    /// and scroll position onto the history stack, then load the target in place (reusing
+    /// `reload_file`'s machinery and re-arming the `--watch` watcher for the new path).
+    fn open_link(&mut self, url: &str) -> Result<()> {
+        if url.contains("://") || url.starts_with('#') || url.starts_with("mailto:") {
+            return Ok(());
+        }
+
+        let target = self
+            .file_path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(url);
+
+        let is_markdown = target
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"))
+            .unwrap_or(false);
+
+        if !is_markdown || !target.is_file() {
+            return Ok(());
+        }
+
+        self.history
+            .push((self.file_path.clone(), self.scroll_offset));
+        self.file_path = target;
+        self.reload_file()?;
+        self.scroll_offset = 0;
+        self.rearm_watcher()?;
+
+        Ok(())
+    }
+
+    /// Return to the file and scroll position visited before the last followed link.
+    fn navigate_back(&mut self) -> Result<()> {
+        let Some((previous_path, previous_scroll_offset)) = self.history.pop() else {
+            return Ok(());
+        };
+
+        self.file_path = previous_path;
+        self.reload_file()?;
+        self.scroll_offset = previous_scroll_offset.min(self.content_length.saturating_sub(1));
+        self.rearm_watcher()?;
+
+        Ok(())
+    }
+
+    /// Re-point the `--watch` file watcher at `self.file_path`, used after navigating to a
+    /// different file via link-following or going back.
+    fn rearm_watcher(&mut self) -> Result<()> {
+        if !self.watching {
+            return Ok(());
+        }
+
+        let (watcher, rx) = Self::watch_file(&self.file_path)?;
+        self.file_watcher = Some(watcher);
+        self.file_change_rx = Some(rx);
+        Ok(())
+    }
+
     fn scroll_up(&mut self) {
         self.scroll_offset = self.scroll_offset.saturating_sub(1);
     }
@@ -324,8 +740,15 @@ impl App {
 
     fn reload_file(&mut self) -> Result<()> {
         self.renderer.load_file(&self.file_path)?;
-        self.rendered_content = self.renderer.render_to_text();
+        self.rendered_content = self.renderer.render_to_text(self.render_width as usize);
         self.content_length = self.rendered_content.lines.len() as u16;
+        self.streamed_content = self.renderer.content().to_string();
+        self.stream_buffer.clear();
+        self.stream_in_code_block = false;
+        self.outline = self.renderer.headings(self.render_width as usize);
+        self.outline_index = 0;
+        self.links = self.renderer.links(self.render_width as usize);
+        self.link_index = 0;
 
         // Adjust scroll offset if content is shorter
         if self.scroll_offset >= self.content_length {
@@ -335,6 +758,155 @@ impl App {
         // Clear search results since content changed
         self.clear_search();
 
+        // Drop marks that now point past the end of the (possibly shorter) document
+        let content_length = self.content_length;
+        self.marks.retain(|_, &mut offset| offset < content_length);
+
+        Ok(())
+    }
+
+    /// Handle a watch-mode file change. If the file only grew by having text appended to it
+    /// (the common case for a document being written live), stream just the new suffix through
+    /// the incremental renderer instead of re-rendering and re-highlighting the whole document.
+    /// Anything else (edits earlier in the file, truncation) falls back to a full reload.
+    fn handle_file_change(&mut self) -> Result<()> {
+        let new_content = fs::read_to_string(&self.file_path)?;
+
+        if let Some(appended) = new_content.strip_prefix(self.streamed_content.as_str()) {
+            let appended = appended.to_string();
+            self.streamed_content = new_content;
+            self.stream_append(&appended);
+            self.clear_search();
+            Ok(())
+        } else {
+            self.reload_file()
+        }
+    }
+
+    /// Accumulate newly-appended file text and flush any display-stable prefix of it: whenever
+    /// the buffer isn't inside a fenced code block and doesn't begin with `#`, `>`, or `|` (a
+    /// heading, blockquote, or table row, which need multi-line context), scan for a safe split
+    /// point and render the finished prefix one line at a time.
+    fn stream_append(&mut self, appended: &str) {
+        self.stream_buffer.push_str(appended);
+
+        loop {
+            let starts_with_block_marker = self
+                .stream_buffer
+                .trim_start()
+                .starts_with(['#', '>', '|']);
+
+            if self.stream_in_code_block || starts_with_block_marker {
+                break;
+            }
+
+            let Some(split_at) = find_safe_split_point(&self.stream_buffer) else {
+                break;
+            };
+
+            let finished = self.stream_buffer[..split_at].to_string();
+            self.stream_buffer.drain(..split_at);
+
+            for line in finished.lines() {
+                if line.trim_start().starts_with("```") {
+                    self.stream_in_code_block = !self.stream_in_code_block;
+                }
+                self.rendered_content.lines.extend(
+                    self.renderer
+                        .render_line_stateless(line, self.render_width as usize),
+                );
+            }
+        }
+
+        self.content_length = self.rendered_content.lines.len() as u16;
+    }
+
+    /// Re-render at `width` if the content pane's display width has changed since the last
+    /// frame, so code blocks/tables/paragraphs reflow to fit the current terminal size.
+    pub fn set_content_width(&mut self, width: u16) {
+        let width = width.max(1);
+        if width == self.render_width {
+            return;
+        }
+
+        self.render_width = width;
+        self.rendered_content = self.renderer.render_to_text(self.render_width as usize);
+        self.content_length = self.rendered_content.lines.len() as u16;
+
+        // Headings/links carry a `line_index` computed at the render width, so a reflow leaves
+        // them just as stale as a `reload_file` unless they're recomputed here too.
+        self.outline = self.renderer.headings(self.render_width as usize);
+        if self.outline_index >= self.outline.len() {
+            self.outline_index = self.outline.len().saturating_sub(1);
+        }
+        self.links = self.renderer.links(self.render_width as usize);
+        if self.link_index >= self.links.len() {
+            self.link_index = self.links.len().saturating_sub(1);
+        }
+
+        if self.scroll_offset >= self.content_length {
+            self.scroll_offset = self.content_length.saturating_sub(1);
+        }
+    }
+
+    /// Record the content pane's visible row count, reported by `ui::draw` each frame, so
+    /// screen-count math (the info panel) reflects the real terminal size.
+    pub fn set_viewport_height(&mut self, height: u16) {
+        self.viewport_height = height.max(1);
+    }
+
+    /// Record the content pane's top border row, reported by `ui::draw` each frame, so mouse
+    /// clicks can be translated into a line within the document.
+    pub fn set_content_area_top(&mut self, top: u16) {
+        self.content_area_top = top;
+    }
+
+    /// Number of lines a single wheel tick scrolls.
+    const MOUSE_SCROLL_LINES: usize = 3;
+
+    /// Scroll on a wheel tick, or translate a left click into a line jump: following the link
+    /// under the cursor if there is one, otherwise scrolling so that line is at the top.
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<()> {
+        match mouse_event.kind {
+            MouseEventKind::ScrollUp => {
+                for _ in 0..Self::MOUSE_SCROLL_LINES {
+                    self.scroll_up();
+                }
+            }
+            MouseEventKind::ScrollDown => {
+                for _ in 0..Self::MOUSE_SCROLL_LINES {
+                    self.scroll_down();
+                }
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.handle_click(mouse_event.row)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Translate a click at absolute terminal `row` into a document line, under the content
+    /// pane's top border (`content_area_top`). Clicks above the content pane are ignored.
+    fn handle_click(&mut self, row: u16) -> Result<()> {
+        let first_content_row = self.content_area_top + 1;
+        if row < first_content_row {
+            return Ok(());
+        }
+
+        let clicked_line = self.scroll_offset + (row - first_content_row);
+
+        if let Some(link) = self
+            .links
+            .iter()
+            .find(|link| link.line_index as u16 == clicked_line)
+            .cloned()
+        {
+            self.open_link(&link.url)?;
+        } else {
+            self.scroll_offset = clicked_line.min(self.content_length.saturating_sub(1));
+        }
+
         Ok(())
     }
 
@@ -348,41 +920,91 @@ impl App {
 
     fn perform_search(&mut self) {
         if self.search_state.query.is_empty() {
+            self.search_state.invalid_pattern = false;
             self.search_state.results.clear();
             self.search_state.current_result_index = None;
             return;
         }
 
-        let query = self.search_state.query.to_lowercase();
-        let mut results = Vec::new();
+        self.recompute_search_results();
+        if !self.search_state.results.is_empty() {
+            self.scroll_to_search_result(0);
+        }
+    }
+
+    /// Re-run the current query against the rendered content, without moving the scroll
+    /// position. Used both by `perform_search` (which jumps to the first result afterwards) and
+    /// when restoring a saved search query on startup (which must not disturb the resumed
+    /// scroll offset).
+    fn recompute_search_results(&mut self) {
+        self.search_state.invalid_pattern = false;
+
+        let mut results = if self.search_state.regex_mode {
+            match self.compile_search_regex() {
+                Ok(regex) => self.find_regex_matches(&regex),
+                Err(_) => {
+                    self.search_state.invalid_pattern = true;
+                    Vec::new()
+                }
+            }
+        } else {
+            self.find_literal_matches()
+        };
 
+        if self.search_state.whole_word {
+            results.retain(|result| {
+                let line_text = Self::line_text(&self.rendered_content.lines[result.line_index]);
+                is_whole_word_match(&line_text, result.char_start, result.char_end)
+            });
+        }
+
+        self.search_state.current_result_index = if results.is_empty() { None } else { Some(0) };
+        self.search_state.results = results;
+    }
+
+    /// Compile the query as a regex, honoring the case-sensitivity toggle.
+    fn compile_search_regex(&self) -> std::result::Result<Regex, regex::Error> {
+        RegexBuilder::new(&self.search_state.query)
+            .case_insensitive(!self.search_state.case_sensitive)
+            .build()
+    }
+
+    fn line_text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    /// Find literal occurrences of the query, delegating to the regex matcher on an escaped
+    /// pattern so case folding happens through `regex`'s Unicode-aware tables instead of a
+    /// `to_lowercase` + byte-offset dance: lowercasing a haystack can change its byte length (e.g.
+    /// `İ` U+0130 is 2 bytes but lowercases to `i̇` (3 bytes), which previously let offsets drift
+    /// off a UTF-8 boundary and panic downstream slicing.
+    fn find_literal_matches(&self) -> Vec<SearchResult> {
+        let pattern = regex::escape(&self.search_state.query);
+        let Ok(regex) = RegexBuilder::new(&pattern)
+            .case_insensitive(!self.search_state.case_sensitive)
+            .build()
+        else {
+            return Vec::new();
+        };
+        self.find_regex_matches(&regex)
+    }
+
+    fn find_regex_matches(&self, regex: &Regex) -> Vec<SearchResult> {
+        let mut results = Vec::new();
         for (line_index, line) in self.rendered_content.lines.iter().enumerate() {
-            let line_text = line
-                .spans
-                .iter()
-                .map(|span| span.content.as_ref())
-                .collect::<String>()
-                .to_lowercase();
-
-            let mut start_pos = 0;
-            while let Some(pos) = line_text[start_pos..].find(&query) {
-                let actual_pos = start_pos + pos;
+            let line_text = Self::line_text(line);
+            for m in regex.find_iter(&line_text) {
                 results.push(SearchResult {
                     line_index,
-                    char_start: actual_pos,
-                    char_end: actual_pos + query.len(),
+                    char_start: m.start(),
+                    char_end: m.end(),
                 });
-                start_pos = actual_pos + 1;
             }
         }
-
-        self.search_state.results = results;
-        if !self.search_state.results.is_empty() {
-            self.search_state.current_result_index = Some(0);
-            self.scroll_to_search_result(0);
-        } else {
-            self.search_state.current_result_index = None;
-        }
+        results
     }
 
     fn next_search_result(&mut self) {
@@ -471,11 +1093,149 @@ impl App {
     pub fn get_search_state(&self) -> &SearchState {
         &self.search_state
     }
+
+    pub fn get_outline(&self) -> &[Heading] {
+        &self.outline
+    }
+
+    pub fn get_outline_index(&self) -> usize {
+        self.outline_index
+    }
+
+    pub fn outline_pane_visible(&self) -> bool {
+        self.outline_pane_visible
+    }
+
+    /// Index into `outline` of the last heading at or before the current scroll position, used
+    /// both to highlight the active section in the outline sidebar and to compute
+    /// `metadata().current_section`.
+    pub fn current_outline_index(&self) -> Option<usize> {
+        self.outline
+            .iter()
+            .rposition(|heading| heading.line_index as u16 <= self.scroll_offset)
+    }
+
+    /// The link currently selected while cycling through on-screen links in `AppMode::Link`.
+    pub fn get_current_link(&self) -> Option<&LinkTarget> {
+        self.links.get(self.link_index)
+    }
+
+    pub fn get_ui_theme(&self) -> &UiTheme {
+        &self.ui_theme
+    }
+
+    pub fn get_ui_theme_picker_index(&self) -> usize {
+        self.ui_theme_picker_index
+    }
+
+    /// Persist the current scroll offset and search query under this file's path, so the next
+    /// launch can resume from here.
+    fn save_position(&self) {
+        position::save(
+            &self.file_path,
+            &SavedPosition {
+                scroll_offset: self.scroll_offset,
+                query: self.search_state.query.clone(),
+            },
+        );
+    }
+
+    /// Compute the current reading-progress snapshot for the info panel.
+    pub fn metadata(&self) -> Metadata {
+        let progress_percent = if self.content_length == 0 {
+            0.0
+        } else {
+            self.scroll_offset as f32 / self.content_length as f32 * 100.0
+        };
+
+        let total_screens = self.content_length.div_ceil(self.viewport_height).max(1);
+        let current_screen = (self.scroll_offset / self.viewport_height) + 1;
+
+        let current_section = self
+            .current_outline_index()
+            .map(|index| self.outline[index].text.clone());
+
+        Metadata {
+            progress_percent,
+            current_screen,
+            total_screens,
+            current_section,
+        }
+    }
+}
+
+/// Scan `buffer` for the first point past a balanced run of inline markdown constructs (code
+/// spans, `**`/`*`/`_` emphasis, `[` link brackets) where a sentence/clause terminator makes it
+/// safe to flush everything before it: a `,`/`.`/`;` followed by whitespace, or a CJK terminator
+/// (`，`/`。`/`；`), which needs no trailing whitespace since CJK text doesn't use spaces between
+/// clauses. Returns the byte offset right after the terminator, or `None` if nothing is safe yet.
+fn find_safe_split_point(buffer: &str) -> Option<usize> {
+    let mut stack: Vec<(char, usize)> = Vec::new();
+    let mut chars = buffer.char_indices().peekable();
+
+    while let Some((byte_pos, ch)) = chars.next() {
+        match ch {
+            '`' | '*' | '_' => {
+                // A run like `**` or ``` `` ``` is one delimiter, closed by a run of the same length.
+                let mut run_len = 1;
+                while chars.peek().map(|&(_, next_ch)| next_ch) == Some(ch) {
+                    chars.next();
+                    run_len += 1;
+                }
+                if stack.last() == Some(&(ch, run_len)) {
+                    stack.pop();
+                } else {
+                    stack.push((ch, run_len));
+                }
+            }
+            '[' => stack.push(('[', 1)),
+            ']' => {
+                if stack.last() == Some(&('[', 1)) {
+                    stack.pop();
+                }
+            }
+            ',' | '.' | ';' if stack.is_empty() => {
+                let followed_by_whitespace = chars
+                    .peek()
+                    .map(|&(_, next_ch)| next_ch.is_whitespace())
+                    .unwrap_or(false);
+                if followed_by_whitespace {
+                    return Some(byte_pos + ch.len_utf8());
+                }
+            }
+            '，' | '。' | '；' if stack.is_empty() => {
+                return Some(byte_pos + ch.len_utf8());
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Whether `line_text[char_start..char_end]` is bordered by non-word characters (or the start/end
+/// of the line) on both sides, same as a regex `\b` anchor on each end of the match.
+fn is_whole_word_match(line_text: &str, char_start: usize, char_end: usize) -> bool {
+    let is_word_char = |ch: char| ch.is_alphanumeric() || ch == '_';
+
+    let left_ok = line_text[..char_start]
+        .chars()
+        .next_back()
+        .map(|ch| !is_word_char(ch))
+        .unwrap_or(true);
+    let right_ok = line_text[char_end..]
+        .chars()
+        .next()
+        .map(|ch| !is_word_char(ch))
+        .unwrap_or(true);
+
+    left_ok && right_ok
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ratatui::style::Color;
     use std::fs;
     use tempfile::NamedTempFile;
 
@@ -483,7 +1243,16 @@ mod tests {
         let temp_file = NamedTempFile::new().unwrap();
         fs::write(&temp_file, "# Test\n\nLine 1\nLine 2\nLine 3\nLine 4\nLine 5\nLine 6\nLine 7\nLine 8\nLine 9\nLine 10\nLine 11\nLine 12\nLine 13\nLine 14\nLine 15\nLine 16\nLine 17\nLine 18\nLine 19\nLine 20").unwrap();
 
-        App::new(temp_file.path().to_path_buf(), false).unwrap()
+        App::new(
+            temp_file.path().to_path_buf(),
+            false,
+            ColorCapability::Truecolor,
+            "base16-ocean.dark",
+            false,
+            false,
+            &[],
+        )
+        .unwrap()
     }
 
     #[test]
@@ -594,4 +1363,618 @@ mod tests {
         assert!(app.search_state.results.is_empty());
         assert_eq!(app.search_state.current_result_index, None);
     }
+
+    #[test]
+    fn test_search_case_sensitivity_toggle() {
+        let mut app = create_test_app();
+        app.search_state.query = "line".to_string();
+        app.perform_search();
+        assert!(
+            !app.search_state.results.is_empty(),
+            "lowercase query should match 'Line' case-insensitively by default"
+        );
+
+        app.search_state.case_sensitive = true;
+        app.perform_search();
+        assert!(
+            app.search_state.results.is_empty(),
+            "lowercase query should no longer match 'Line' once case-sensitive"
+        );
+
+        app.search_state.query = "Line".to_string();
+        app.perform_search();
+        assert!(!app.search_state.results.is_empty());
+    }
+
+    #[test]
+    fn test_search_case_insensitive_handles_non_ascii_byte_length_changes() {
+        // `İ` (U+0130, 2 bytes) lowercases to `i̇` (3 bytes): a naive lowercase-the-haystack
+        // search would compute offsets against the 3-byte form and then slice the original
+        // 2-byte text at them, landing off a UTF-8 boundary.
+        let temp_file = NamedTempFile::new().unwrap();
+        fs::write(&temp_file, "Visit İstanbul today").unwrap();
+
+        let mut app = App::new(
+            temp_file.path().to_path_buf(),
+            false,
+            ColorCapability::Truecolor,
+            "base16-ocean.dark",
+            false,
+            false,
+            &[],
+        )
+        .unwrap();
+
+        app.search_state.query = "istanbul".to_string();
+        app.perform_search();
+
+        assert!(!app.search_state.results.is_empty());
+        let result = &app.search_state.results[0];
+        let line_text = App::line_text(&app.rendered_content.lines[result.line_index]);
+        assert_eq!(&line_text[result.char_start..result.char_end], "İstanbul");
+    }
+
+    #[test]
+    fn test_search_regex_mode() {
+        let mut app = create_test_app();
+        app.search_state.regex_mode = true;
+        app.search_state.query = r"Line \d+".to_string();
+        app.perform_search();
+
+        assert!(!app.search_state.results.is_empty());
+        assert!(!app.search_state.invalid_pattern);
+    }
+
+    #[test]
+    fn test_search_invalid_regex_reports_gracefully() {
+        let mut app = create_test_app();
+        app.search_state.regex_mode = true;
+        app.search_state.query = "(unclosed".to_string();
+        app.perform_search();
+
+        assert!(app.search_state.invalid_pattern);
+        assert!(app.search_state.results.is_empty());
+    }
+
+    #[test]
+    fn test_search_whole_word_toggle() {
+        let mut app = create_test_app();
+        app.search_state.query = "Lin".to_string();
+        app.perform_search();
+        assert!(
+            !app.search_state.results.is_empty(),
+            "'Lin' should match as a substring of 'Line' by default"
+        );
+
+        app.search_state.whole_word = true;
+        app.perform_search();
+        assert!(
+            app.search_state.results.is_empty(),
+            "'Lin' is not bordered by a non-word character on its right, so whole-word mode should reject it"
+        );
+
+        app.search_state.query = "Line".to_string();
+        app.perform_search();
+        assert!(
+            !app.search_state.results.is_empty(),
+            "'Line' is a full word in the fixture, so whole-word mode should still match it"
+        );
+    }
+
+    #[test]
+    fn test_is_whole_word_match_checks_both_borders() {
+        assert!(is_whole_word_match("Line 1", 0, 4));
+        assert!(!is_whole_word_match("Line 1", 0, 3));
+        assert!(is_whole_word_match("a cat sat", 2, 5));
+        assert!(!is_whole_word_match("a cats sat", 2, 5));
+    }
+
+    #[test]
+    fn test_find_safe_split_point_on_sentence_terminator() {
+        let split_at = find_safe_split_point("one, two.").unwrap();
+        assert_eq!(&"one, two."[..split_at], "one,");
+    }
+
+    #[test]
+    fn test_find_safe_split_point_ignores_terminators_inside_code_span() {
+        // The period inside the code span shouldn't count; only the one after it should.
+        assert!(find_safe_split_point("see `a.b` and more").is_none());
+        let split_at = find_safe_split_point("see `a.b` done. more").unwrap();
+        assert_eq!(&"see `a.b` done. more"[..split_at], "see `a.b` done.");
+    }
+
+    #[test]
+    fn test_find_safe_split_point_cjk_terminator_needs_no_whitespace() {
+        let split_at = find_safe_split_point("你好。世界").unwrap();
+        assert_eq!(&"你好。世界"[..split_at], "你好。");
+    }
+
+    #[test]
+    fn test_find_safe_split_point_none_without_terminator() {
+        assert!(find_safe_split_point("no terminator here yet").is_none());
+    }
+
+    #[test]
+    fn test_stream_append_flushes_finished_sentences() {
+        let mut app = create_test_app();
+        app.rendered_content.lines.clear();
+        app.stream_buffer.clear();
+
+        let lines_before = app.rendered_content.lines.len();
+        app.stream_append("First sentence. Second sentence without end");
+
+        // The first sentence is safe to flush; the unterminated remainder stays buffered.
+        assert!(app.rendered_content.lines.len() > lines_before);
+        assert_eq!(app.stream_buffer, " Second sentence without end");
+    }
+
+    #[test]
+    fn test_stream_append_wraps_at_current_render_width_not_the_hardcoded_default() {
+        let mut app = create_test_app();
+        app.render_width = 10;
+        app.rendered_content.lines.clear();
+        app.stream_buffer.clear();
+
+        let sentence = "This is a rather long line of streamed text that needs wrapping.";
+        app.stream_append(&format!("{} ", sentence));
+
+        let lines_at_app_width = app.rendered_content.lines.len();
+        let lines_at_hardcoded_default = app
+            .renderer
+            .render_line_stateless(sentence, DEFAULT_RENDER_WIDTH)
+            .len();
+
+        assert!(
+            lines_at_app_width > lines_at_hardcoded_default,
+            "streamed text should wrap to the app's narrow render width, not the wider default"
+        );
+    }
+
+    #[test]
+    fn test_mark_and_jump() {
+        let mut app = create_test_app();
+
+        app.scroll_offset = 7;
+        app.handle_mark_mode_key(KeyCode::Char('a'));
+        assert_eq!(app.mode, AppMode::Normal);
+        assert_eq!(app.marks.get(&'a'), Some(&7));
+
+        app.scroll_offset = 0;
+        app.handle_jump_mode_key(KeyCode::Char('a'));
+        assert_eq!(app.scroll_offset, 7);
+
+        // An unset mark is ignored.
+        app.handle_jump_mode_key(KeyCode::Char('z'));
+        assert_eq!(app.scroll_offset, 7);
+    }
+
+    #[test]
+    fn test_reload_file_clears_marks_past_content_length() {
+        let mut app = create_test_app();
+        app.marks.insert('a', 2);
+        app.marks.insert('b', app.content_length + 100);
+
+        app.reload_file().unwrap();
+
+        assert!(app.marks.contains_key(&'a'));
+        assert!(!app.marks.contains_key(&'b'));
+    }
+
+    #[test]
+    fn test_set_content_width_recomputes_outline_and_link_line_indices() {
+        let temp_file = NamedTempFile::new().unwrap();
+        fs::write(
+            &temp_file,
+            "Lorem ipsum dolor sit amet consectetur adipiscing elit sed do eiusmod tempor \
+             incididunt ut labore et dolore magna aliqua sed ut perspiciatis unde omnis iste \
+             natus error sit voluptatem accusantium doloremque laudantium.\n\n\
+             # Heading\n\nSee [a link](a.md).",
+        )
+        .unwrap();
+
+        let mut app = App::new(
+            temp_file.path().to_path_buf(),
+            false,
+            ColorCapability::Truecolor,
+            "base16-ocean.dark",
+            false,
+            false,
+            &[],
+        )
+        .unwrap();
+
+        let wide_heading_line = app.outline[0].line_index;
+        let wide_link_line = app.links[0].line_index;
+
+        app.set_content_width(10);
+
+        assert_ne!(
+            wide_heading_line, app.outline[0].line_index,
+            "a narrower width rewraps the preceding paragraph onto more lines, shifting the heading"
+        );
+        assert_ne!(wide_link_line, app.links[0].line_index);
+
+        // The recomputed indices must stay in bounds.
+        assert!(app.outline_index < app.outline.len());
+        assert!(app.link_index < app.links.len());
+    }
+
+    #[test]
+    fn test_outline_built_from_headings() {
+        let app = create_test_app();
+        // create_test_app's fixture starts with a single "# Test" heading.
+        assert_eq!(app.outline.len(), 1);
+        assert_eq!(app.outline[0].text, "Test");
+    }
+
+    #[test]
+    fn test_outline_navigation_and_jump() {
+        let mut app = create_test_app();
+        app.outline.push(Heading {
+            level: 1,
+            text: "Second".to_string(),
+            line_index: 5,
+        });
+
+        app.start_outline();
+        assert_eq!(app.mode, AppMode::Outline);
+        assert_eq!(app.outline_index, 0);
+
+        app.handle_outline_mode_key(KeyCode::Char('j'));
+        assert_eq!(app.outline_index, 1);
+
+        app.handle_outline_mode_key(KeyCode::Enter);
+        assert_eq!(app.mode, AppMode::Normal);
+        assert_eq!(app.scroll_offset, 5);
+    }
+
+    #[test]
+    fn test_toggle_outline_pane() {
+        let mut app = create_test_app();
+        assert!(!app.outline_pane_visible());
+
+        app.handle_normal_mode_key(KeyCode::Char('O'));
+        assert!(app.outline_pane_visible());
+
+        app.handle_normal_mode_key(KeyCode::Char('O'));
+        assert!(!app.outline_pane_visible());
+    }
+
+    #[test]
+    fn test_current_outline_index_tracks_scroll_position() {
+        let mut app = create_test_app();
+        app.outline = vec![
+            Heading {
+                level: 1,
+                text: "Intro".to_string(),
+                line_index: 0,
+            },
+            Heading {
+                level: 2,
+                text: "Details".to_string(),
+                line_index: 10,
+            },
+        ];
+
+        app.scroll_offset = 3;
+        assert_eq!(app.current_outline_index(), Some(0));
+
+        app.scroll_offset = 12;
+        assert_eq!(app.current_outline_index(), Some(1));
+    }
+
+    #[test]
+    fn test_color_overrides_are_applied_on_top_of_the_selected_theme() {
+        let temp_file = NamedTempFile::new().unwrap();
+        fs::write(&temp_file, "# Test").unwrap();
+
+        let app = App::new(
+            temp_file.path().to_path_buf(),
+            false,
+            ColorCapability::Truecolor,
+            "base16-ocean.dark",
+            false,
+            false,
+            &[("search_match".to_string(), "#112233".to_string())],
+        )
+        .unwrap();
+
+        assert_eq!(app.ui_theme.search_match, Color::Rgb(0x11, 0x22, 0x33));
+        // Untouched fields keep the base theme's values.
+        assert_eq!(app.ui_theme.border, theme::DARK.border);
+    }
+
+    #[test]
+    fn test_invalid_color_override_is_ignored() {
+        let temp_file = NamedTempFile::new().unwrap();
+        fs::write(&temp_file, "# Test").unwrap();
+
+        let app = App::new(
+            temp_file.path().to_path_buf(),
+            false,
+            ColorCapability::Truecolor,
+            "base16-ocean.dark",
+            false,
+            false,
+            &[("search_match".to_string(), "not-a-color".to_string())],
+        )
+        .unwrap();
+
+        assert_eq!(app.ui_theme, theme::DARK);
+    }
+
+    #[test]
+    fn test_theme_picker_cycles_and_previews_live() {
+        let mut app = create_test_app();
+        app.start_theme_picker();
+        assert_eq!(app.mode, AppMode::ThemePicker);
+        assert_eq!(app.ui_theme, theme::DARK);
+
+        app.handle_theme_picker_mode_key(KeyCode::Char('j'));
+        assert_eq!(app.ui_theme, theme::LIGHT);
+
+        app.handle_theme_picker_mode_key(KeyCode::Char('j'));
+        assert_eq!(app.ui_theme, theme::AYU);
+
+        app.handle_theme_picker_mode_key(KeyCode::Char('k'));
+        assert_eq!(app.ui_theme, theme::LIGHT);
+    }
+
+    #[test]
+    fn test_theme_picker_esc_reverts_to_previous_theme() {
+        let mut app = create_test_app();
+        app.start_theme_picker();
+
+        app.handle_theme_picker_mode_key(KeyCode::Char('j'));
+        assert_eq!(app.ui_theme, theme::LIGHT);
+
+        app.handle_theme_picker_mode_key(KeyCode::Esc);
+        assert_eq!(app.mode, AppMode::Normal);
+        assert_eq!(app.ui_theme, theme::DARK);
+    }
+
+    #[test]
+    fn test_metadata_progress_and_screen_count() {
+        let mut app = create_test_app();
+        app.viewport_height = 5;
+        app.content_length = 20;
+        app.scroll_offset = 10;
+
+        let metadata = app.metadata();
+
+        assert_eq!(metadata.progress_percent, 50.0);
+        assert_eq!(metadata.total_screens, 4);
+        assert_eq!(metadata.current_screen, 3);
+    }
+
+    #[test]
+    fn test_metadata_current_section_is_nearest_preceding_heading() {
+        let mut app = create_test_app();
+        app.outline = vec![
+            Heading {
+                level: 1,
+                text: "Intro".to_string(),
+                line_index: 0,
+            },
+            Heading {
+                level: 2,
+                text: "Details".to_string(),
+                line_index: 10,
+            },
+        ];
+        app.scroll_offset = 12;
+
+        assert_eq!(
+            app.metadata().current_section,
+            Some("Details".to_string())
+        );
+    }
+
+    #[test]
+    fn test_info_mode_toggles_back_to_normal() {
+        let mut app = create_test_app();
+        app.mode = AppMode::Info;
+        app.handle_info_mode_key(KeyCode::Char('i'));
+        assert_eq!(app.mode, AppMode::Normal);
+    }
+
+    #[test]
+    fn test_link_cycle_selects_on_screen_links() {
+        let mut app = create_test_app();
+        app.links = vec![
+            LinkTarget {
+                url: "a.md".to_string(),
+                line_index: 0,
+            },
+            LinkTarget {
+                url: "b.md".to_string(),
+                line_index: 100,
+            },
+        ];
+        app.viewport_height = 20;
+        app.scroll_offset = 0;
+
+        app.start_link_cycle();
+        assert_eq!(app.mode, AppMode::Link);
+        assert_eq!(app.get_current_link().unwrap().url, "a.md");
+
+        // Cycling wraps back to the only link visible in the current viewport.
+        app.handle_link_mode_key(KeyCode::Char('l'));
+        assert_eq!(app.get_current_link().unwrap().url, "a.md");
+
+        app.handle_link_mode_key(KeyCode::Esc);
+        assert_eq!(app.mode, AppMode::Normal);
+    }
+
+    #[test]
+    fn test_open_link_pushes_history_and_loads_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let origin_path = dir.path().join("origin.md");
+        let target_path = dir.path().join("target.md");
+        fs::write(&origin_path, "# Origin\n\n[to target](target.md)").unwrap();
+        fs::write(&target_path, "# Target\n\nOther content").unwrap();
+
+        let mut app = App::new(
+            origin_path.clone(),
+            false,
+            ColorCapability::Truecolor,
+            "base16-ocean.dark",
+            false,
+            false,
+            &[],
+        )
+        .unwrap();
+        app.scroll_offset = 2;
+
+        app.open_link("target.md").unwrap();
+
+        assert_eq!(app.file_path, target_path);
+        assert_eq!(app.history, vec![(origin_path, 2)]);
+        assert_eq!(app.get_file_name(), "target.md");
+    }
+
+    #[test]
+    fn test_open_link_ignores_non_relative_targets() {
+        let mut app = create_test_app();
+        let file_path_before = app.file_path.clone();
+
+        app.open_link("https://example.com").unwrap();
+        app.open_link("#section").unwrap();
+        app.open_link("mailto:someone@example.com").unwrap();
+
+        assert_eq!(app.file_path, file_path_before);
+        assert!(app.history.is_empty());
+    }
+
+    #[test]
+    fn test_navigate_back_restores_previous_file_and_position() {
+        let dir = tempfile::tempdir().unwrap();
+        let origin_path = dir.path().join("origin.md");
+        let target_path = dir.path().join("target.md");
+        fs::write(&origin_path, "# Origin\n\n[to target](target.md)").unwrap();
+        fs::write(&target_path, "# Target\n\nOther content").unwrap();
+
+        let mut app = App::new(
+            origin_path.clone(),
+            false,
+            ColorCapability::Truecolor,
+            "base16-ocean.dark",
+            false,
+            false,
+            &[],
+        )
+        .unwrap();
+        app.scroll_offset = 2;
+        app.open_link("target.md").unwrap();
+
+        app.navigate_back().unwrap();
+
+        assert_eq!(app.file_path, origin_path);
+        assert_eq!(app.scroll_offset, 2);
+        assert!(app.history.is_empty());
+    }
+
+    #[test]
+    fn test_mouse_wheel_scrolls_a_few_lines_per_tick() {
+        let mut app = create_test_app();
+        app.scroll_offset = 10;
+
+        app.handle_mouse_event(MouseEvent {
+            kind: MouseEventKind::ScrollUp,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        })
+        .unwrap();
+        assert_eq!(app.scroll_offset, 10 - App::MOUSE_SCROLL_LINES as u16);
+
+        app.handle_mouse_event(MouseEvent {
+            kind: MouseEventKind::ScrollDown,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        })
+        .unwrap();
+        assert_eq!(app.scroll_offset, 10);
+    }
+
+    #[test]
+    fn test_click_jumps_to_clicked_line() {
+        let mut app = create_test_app();
+        app.content_area_top = 2;
+        app.scroll_offset = 5;
+
+        // Row 5 is 2 lines below the first content row (content_area_top + 1 = 3).
+        app.handle_mouse_event(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 0,
+            row: 5,
+            modifiers: KeyModifiers::NONE,
+        })
+        .unwrap();
+
+        assert_eq!(app.scroll_offset, 7);
+    }
+
+    #[test]
+    fn test_click_above_content_pane_is_ignored() {
+        let mut app = create_test_app();
+        app.content_area_top = 2;
+        app.scroll_offset = 5;
+
+        app.handle_mouse_event(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 0,
+            row: 1,
+            modifiers: KeyModifiers::NONE,
+        })
+        .unwrap();
+
+        assert_eq!(app.scroll_offset, 5);
+    }
+
+    #[test]
+    fn test_click_on_link_line_follows_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let origin_path = dir.path().join("origin.md");
+        let target_path = dir.path().join("target.md");
+        fs::write(&origin_path, "# Origin\n\n[to target](target.md)").unwrap();
+        fs::write(&target_path, "# Target\n\nOther content").unwrap();
+
+        let mut app = App::new(
+            origin_path,
+            false,
+            ColorCapability::Truecolor,
+            "base16-ocean.dark",
+            false,
+            false,
+            &[],
+        )
+        .unwrap();
+        app.content_area_top = 0;
+        app.scroll_offset = 0;
+        let link_line = app.links[0].line_index as u16;
+
+        app.handle_mouse_event(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 0,
+            row: link_line + 1,
+            modifiers: KeyModifiers::NONE,
+        })
+        .unwrap();
+
+        assert_eq!(app.file_path, target_path);
+    }
+
+    #[test]
+    fn test_stream_append_waits_out_headings() {
+        let mut app = create_test_app();
+        app.stream_buffer.clear();
+
+        app.stream_append("# Title.");
+        assert_eq!(
+            app.stream_buffer, "# Title.",
+            "a heading-like buffer should wait for a full reload, not stream-split"
+        );
+    }
 }
@@ -0,0 +1,249 @@
+// Copyright 2025 Ray Krueger <raykrueger@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crossterm::event::KeyCode;
+use std::collections::HashMap;
+
+/// A normal-mode behavior a key can be bound to, decoupling input handling from behavior so
+/// `handle_normal_mode_key` can stay table-driven.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    StartSearch,
+    Mark,
+    Jump,
+    Outline,
+    ToggleOutlinePane,
+    Info,
+    NextResult,
+    PreviousResult,
+    Reload,
+    LinkCycle,
+    NavigateBack,
+    ThemePicker,
+    ScrollUp,
+    ScrollDown,
+    HalfPageUp,
+    HalfPageDown,
+    PageUp,
+    PageDown,
+    GoToTop,
+    GoToBottom,
+    ScrollUpFive,
+    ScrollDownFive,
+    GoToMiddle,
+    ScrollUpTen,
+    ScrollDownTen,
+}
+
+/// Maps a pressed `KeyCode` to the `Action` it triggers in normal mode. Built from the defaults
+/// below, then layered with any overrides from the user's config file.
+#[derive(Debug, Clone)]
+pub struct KeyBindings {
+    bindings: HashMap<KeyCode, Action>,
+}
+
+impl KeyBindings {
+    pub fn action_for(&self, key_code: KeyCode) -> Option<Action> {
+        self.bindings.get(&key_code).copied()
+    }
+
+    /// Build the default bindings, then apply overrides from `mdless/keybindings.toml` in the
+    /// user's config dir if present. A missing or malformed config is silently ignored, same as
+    /// `load_user_themes`, so startup never fails over a remap typo.
+    pub fn load() -> Self {
+        let mut key_bindings = Self::defaults();
+
+        if let Some(config_dir) = dirs::config_dir() {
+            let path = config_dir.join("mdless").join("keybindings.toml");
+            if let Ok(contents) = std::fs::read_to_string(&path) {
+                key_bindings.apply_overrides(&contents);
+            }
+        }
+
+        key_bindings
+    }
+
+    fn defaults() -> Self {
+        let mut bindings = HashMap::new();
+
+        bindings.insert(KeyCode::Char('q'), Action::Quit);
+        bindings.insert(KeyCode::Char('/'), Action::StartSearch);
+        bindings.insert(KeyCode::Char('m'), Action::Mark);
+        bindings.insert(KeyCode::Char('\''), Action::Jump);
+        bindings.insert(KeyCode::Char('o'), Action::Outline);
+        bindings.insert(KeyCode::Char('O'), Action::ToggleOutlinePane);
+        bindings.insert(KeyCode::Char('i'), Action::Info);
+        bindings.insert(KeyCode::Char('n'), Action::NextResult);
+        bindings.insert(KeyCode::Char('N'), Action::PreviousResult);
+        bindings.insert(KeyCode::Char('r'), Action::Reload);
+        bindings.insert(KeyCode::Char('l'), Action::LinkCycle);
+        bindings.insert(KeyCode::Backspace, Action::NavigateBack);
+        bindings.insert(KeyCode::Char('t'), Action::ThemePicker);
+
+        bindings.insert(KeyCode::Up, Action::ScrollUp);
+        bindings.insert(KeyCode::Char('k'), Action::ScrollUp);
+        bindings.insert(KeyCode::Down, Action::ScrollDown);
+        bindings.insert(KeyCode::Char('j'), Action::ScrollDown);
+        bindings.insert(KeyCode::Char('u'), Action::HalfPageUp);
+        bindings.insert(KeyCode::Char('d'), Action::HalfPageDown);
+        bindings.insert(KeyCode::PageUp, Action::PageUp);
+        bindings.insert(KeyCode::Char('b'), Action::PageUp);
+        bindings.insert(KeyCode::PageDown, Action::PageDown);
+        bindings.insert(KeyCode::Char('f'), Action::PageDown);
+        bindings.insert(KeyCode::Home, Action::GoToTop);
+        bindings.insert(KeyCode::Char('g'), Action::GoToTop);
+        bindings.insert(KeyCode::End, Action::GoToBottom);
+        bindings.insert(KeyCode::Char('G'), Action::GoToBottom);
+        bindings.insert(KeyCode::Char('K'), Action::ScrollUpFive);
+        bindings.insert(KeyCode::Char('J'), Action::ScrollDownFive);
+        bindings.insert(KeyCode::Char('M'), Action::GoToMiddle);
+        bindings.insert(KeyCode::Char('U'), Action::ScrollUpTen);
+        bindings.insert(KeyCode::Char('D'), Action::ScrollDownTen);
+
+        Self { bindings }
+    }
+
+    /// Apply `key = "action"` override lines (blank lines and `#` comments are skipped). Keys
+    /// name either a single character (`"k"`) or a named key (`"Up"`, `"PageDown"`,
+    /// `"Backspace"`, ...); unrecognized keys or actions are skipped rather than failing the rest
+    /// of the file.
+    fn apply_overrides(&mut self, contents: &str) {
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key_part, action_part)) = line.split_once('=') else {
+                continue;
+            };
+            let Some(key_code) = parse_key_name(key_part.trim().trim_matches('"')) else {
+                continue;
+            };
+            let Some(action) = parse_action_name(action_part.trim().trim_matches('"')) else {
+                continue;
+            };
+
+            self.bindings.insert(key_code, action);
+        }
+    }
+}
+
+/// Parse a config key name into the `KeyCode` it refers to: a single character, or one of the
+/// named keys used by the default bindings.
+fn parse_key_name(name: &str) -> Option<KeyCode> {
+    match name {
+        "Up" => Some(KeyCode::Up),
+        "Down" => Some(KeyCode::Down),
+        "Left" => Some(KeyCode::Left),
+        "Right" => Some(KeyCode::Right),
+        "Home" => Some(KeyCode::Home),
+        "End" => Some(KeyCode::End),
+        "PageUp" => Some(KeyCode::PageUp),
+        "PageDown" => Some(KeyCode::PageDown),
+        "Backspace" => Some(KeyCode::Backspace),
+        "Enter" => Some(KeyCode::Enter),
+        "Esc" => Some(KeyCode::Esc),
+        "Tab" => Some(KeyCode::Tab),
+        _ => {
+            let mut chars = name.chars();
+            let ch = chars.next()?;
+            chars.next().is_none().then_some(KeyCode::Char(ch))
+        }
+    }
+}
+
+/// Parse a config action name into the `Action` it refers to.
+fn parse_action_name(name: &str) -> Option<Action> {
+    match name {
+        "Quit" => Some(Action::Quit),
+        "StartSearch" => Some(Action::StartSearch),
+        "Mark" => Some(Action::Mark),
+        "Jump" => Some(Action::Jump),
+        "Outline" => Some(Action::Outline),
+        "ToggleOutlinePane" => Some(Action::ToggleOutlinePane),
+        "Info" => Some(Action::Info),
+        "NextResult" => Some(Action::NextResult),
+        "PreviousResult" => Some(Action::PreviousResult),
+        "Reload" => Some(Action::Reload),
+        "LinkCycle" => Some(Action::LinkCycle),
+        "NavigateBack" => Some(Action::NavigateBack),
+        "ThemePicker" => Some(Action::ThemePicker),
+        "ScrollUp" => Some(Action::ScrollUp),
+        "ScrollDown" => Some(Action::ScrollDown),
+        "HalfPageUp" => Some(Action::HalfPageUp),
+        "HalfPageDown" => Some(Action::HalfPageDown),
+        "PageUp" => Some(Action::PageUp),
+        "PageDown" => Some(Action::PageDown),
+        "GoToTop" => Some(Action::GoToTop),
+        "GoToBottom" => Some(Action::GoToBottom),
+        "ScrollUpFive" => Some(Action::ScrollUpFive),
+        "ScrollDownFive" => Some(Action::ScrollDownFive),
+        "GoToMiddle" => Some(Action::GoToMiddle),
+        "ScrollUpTen" => Some(Action::ScrollUpTen),
+        "ScrollDownTen" => Some(Action::ScrollDownTen),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_cover_quit_and_movement() {
+        let key_bindings = KeyBindings::defaults();
+        assert_eq!(
+            key_bindings.action_for(KeyCode::Char('q')),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            key_bindings.action_for(KeyCode::Char('j')),
+            Some(Action::ScrollDown)
+        );
+        assert_eq!(key_bindings.action_for(KeyCode::Char('z')), None);
+    }
+
+    #[test]
+    fn test_apply_overrides_remaps_a_key() {
+        let mut key_bindings = KeyBindings::defaults();
+        key_bindings.apply_overrides(
+            "# remap quit to Esc\n\"Esc\" = \"Quit\"\n\"j\" = \"GoToBottom\"\n",
+        );
+
+        assert_eq!(key_bindings.action_for(KeyCode::Esc), Some(Action::Quit));
+        assert_eq!(
+            key_bindings.action_for(KeyCode::Char('j')),
+            Some(Action::GoToBottom)
+        );
+        // Untouched defaults survive the override pass.
+        assert_eq!(
+            key_bindings.action_for(KeyCode::Char('q')),
+            Some(Action::Quit)
+        );
+    }
+
+    #[test]
+    fn test_apply_overrides_ignores_unrecognized_entries() {
+        let mut key_bindings = KeyBindings::defaults();
+        key_bindings.apply_overrides("\"NotAKey\" = \"Quit\"\n\"q\" = \"NotAnAction\"\n");
+
+        // The malformed key is dropped, and the malformed action leaves 'q' bound as before.
+        assert_eq!(
+            key_bindings.action_for(KeyCode::Char('q')),
+            Some(Action::Quit)
+        );
+    }
+}
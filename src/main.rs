@@ -18,10 +18,14 @@ use std::path::PathBuf;
 
 mod app;
 mod error;
+mod keybindings;
 mod markdown;
+mod position;
+mod theme;
 mod ui;
 
 use app::App;
+use markdown::{ColorCapability, ColorChoice, MarkdownRenderer};
 
 #[derive(Parser)]
 #[command(name = "mdview")]
@@ -29,25 +33,84 @@ use app::App;
 #[command(version)]
 struct Cli {
     /// Path to the markdown file to view
-    file: PathBuf,
+    file: Option<PathBuf>,
 
     /// Enable file watching for live updates
     #[arg(short, long)]
     watch: bool,
+
+    /// Color capability for syntax highlighting: always, auto, never, 256, or truecolor
+    #[arg(long, value_enum, default_value = "auto")]
+    color: ColorChoice,
+
+    /// Syntax highlighting theme name (defaults to MDLESS_THEME, then light/dark auto-detection)
+    #[arg(long)]
+    theme: Option<String>,
+
+    /// List available syntax highlighting themes and exit
+    #[arg(long)]
+    list_themes: bool,
+
+    /// Show line numbers inside code blocks
+    #[arg(short = 'n', long)]
+    line_numbers: bool,
+
+    /// Don't restore (or save) the last read position for this file; always start at the top
+    #[arg(long)]
+    no_resume: bool,
+
+    /// Override a UI chrome color: FIELD=COLOR, e.g. --ui-color search_match=#ffcc00. Repeatable;
+    /// takes precedence over mdless/colors.toml. FIELD is one of: header_title, header_text,
+    /// border, content_fg, search_match, search_nomatch, footer_title, help_text, scrollbar.
+    /// COLOR is a named terminal color or a #rrggbb hex value.
+    #[arg(long = "ui-color", value_name = "FIELD=COLOR")]
+    ui_color: Vec<String>,
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    if !cli.file.exists() {
-        anyhow::bail!("File does not exist: {}", cli.file.display());
+    if cli.list_themes {
+        for name in MarkdownRenderer::list_theme_names() {
+            println!("{}", name);
+        }
+        return Ok(());
     }
 
-    if !cli.file.is_file() {
-        anyhow::bail!("Path is not a file: {}", cli.file.display());
+    let file = cli
+        .file
+        .ok_or_else(|| anyhow::anyhow!("the following required arguments were not provided: FILE"))?;
+
+    if !file.exists() {
+        anyhow::bail!("File does not exist: {}", file.display());
+    }
+
+    if !file.is_file() {
+        anyhow::bail!("Path is not a file: {}", file.display());
     }
 
-    let mut app = App::new(cli.file, cli.watch)?;
+    let color_capability = ColorCapability::resolve(cli.color);
+    let theme_name = cli
+        .theme
+        .or_else(|| std::env::var("MDLESS_THEME").ok())
+        .unwrap_or_else(|| "auto".to_string());
+
+    let color_overrides: Vec<(String, String)> = cli
+        .ui_color
+        .iter()
+        .filter_map(|entry| entry.split_once('='))
+        .map(|(field, value)| (field.trim().to_string(), value.trim().to_string()))
+        .collect();
+
+    let mut app = App::new(
+        file,
+        cli.watch,
+        color_capability,
+        &theme_name,
+        cli.line_numbers,
+        !cli.no_resume,
+        &color_overrides,
+    )?;
     app.run()?;
 
     Ok(())
@@ -1,27 +1,380 @@
-use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use pulldown_cmark::{Alignment, Event, Options, Parser, Tag, TagEnd};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span, Text};
+use std::env;
 use std::fs;
 use std::path::Path;
 use syntect::easy::HighlightLines;
 use syntect::highlighting::{Style as SyntectStyle, ThemeSet};
 use syntect::parsing::SyntaxSet;
 use syntect::util::LinesWithEndings;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::error::Result;
 
+/// Default render width used before the terminal's actual size is known (e.g. by callers that
+/// don't have a terminal at all, like the `render_to_text` tests).
+pub const DEFAULT_RENDER_WIDTH: usize = 79;
+
+/// Render width is clamped to this range so a sliver of a terminal doesn't collapse borders to
+/// nothing and a very wide one doesn't stretch code blocks absurdly far from the text.
+const MIN_RENDER_WIDTH: usize = 40;
+const MAX_RENDER_WIDTH: usize = 120;
+
+/// Number of spaces a `\t` expands to when measuring display width, unless overridden.
+const DEFAULT_TAB_WIDTH: usize = 4;
+
+/// User-facing `--color` CLI choice; resolved to a [`ColorCapability`] at startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ColorChoice {
+    Always,
+    Auto,
+    Never,
+    #[value(name = "256")]
+    Color256,
+    Truecolor,
+}
+
+/// The color depth syntax-highlighting spans are downsampled to before rendering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorCapability {
+    Truecolor,
+    Indexed256,
+    Ansi16,
+}
+
+impl ColorCapability {
+    /// Resolve the effective capability from a `--color` choice, detecting the terminal's
+    /// advertised capability (`COLORTERM`/`TERM`) for `Auto`.
+    pub fn resolve(choice: ColorChoice) -> Self {
+        match choice {
+            ColorChoice::Always | ColorChoice::Truecolor => ColorCapability::Truecolor,
+            ColorChoice::Color256 => ColorCapability::Indexed256,
+            // "never" downsample to the most conservative depth so color is never dropped outright.
+            ColorChoice::Never => ColorCapability::Ansi16,
+            ColorChoice::Auto => Self::detect(),
+        }
+    }
+
+    fn detect() -> Self {
+        if let Ok(colorterm) = env::var("COLORTERM")
+            && (colorterm.contains("truecolor") || colorterm.contains("24bit"))
+        {
+            return ColorCapability::Truecolor;
+        }
+
+        if let Ok(term) = env::var("TERM")
+            && term.contains("256color")
+        {
+            return ColorCapability::Indexed256;
+        }
+
+        ColorCapability::Ansi16
+    }
+}
+
+/// The 16 basic ANSI colors with their conventional RGB values, used to find the nearest match
+/// when downsampling to [`ColorCapability::Ansi16`].
+const ANSI16_PALETTE: [(Color, u8, u8, u8); 16] = [
+    (Color::Black, 0, 0, 0),
+    (Color::Red, 128, 0, 0),
+    (Color::Green, 0, 128, 0),
+    (Color::Yellow, 128, 128, 0),
+    (Color::Blue, 0, 0, 128),
+    (Color::Magenta, 128, 0, 128),
+    (Color::Cyan, 0, 128, 128),
+    (Color::Gray, 192, 192, 192),
+    (Color::DarkGray, 128, 128, 128),
+    (Color::LightRed, 255, 0, 0),
+    (Color::LightGreen, 0, 255, 0),
+    (Color::LightYellow, 255, 255, 0),
+    (Color::LightBlue, 0, 0, 255),
+    (Color::LightMagenta, 255, 0, 255),
+    (Color::LightCyan, 0, 255, 255),
+    (Color::White, 255, 255, 255),
+];
+
+/// The 6 RGB levels used by the xterm 256-color 6x6x6 cube.
+const XTERM_CUBE_LEVELS: [u16; 6] = [0, 95, 135, 175, 215, 255];
+
+fn squared_distance(r1: u8, g1: u8, b1: u8, r2: u16, g2: u16, b2: u16) -> i32 {
+    let dr = r1 as i32 - r2 as i32;
+    let dg = g1 as i32 - g2 as i32;
+    let db = b1 as i32 - b2 as i32;
+    dr * dr + dg * dg + db * db
+}
+
+/// Map an RGB triple to the nearest xterm 256-color palette index, comparing the 6x6x6 color
+/// cube against the 24-step grayscale ramp and keeping whichever is closer.
+fn rgb_to_xterm256(r: u8, g: u8, b: u8) -> u8 {
+    let channel_to_cube_level = |c: u8| -> u8 {
+        if c < 48 {
+            0
+        } else if c < 115 {
+            1
+        } else {
+            ((c as u16 - 35) / 40) as u8
+        }
+    };
+
+    let cube_r = channel_to_cube_level(r);
+    let cube_g = channel_to_cube_level(g);
+    let cube_b = channel_to_cube_level(b);
+    let cube_index = 16 + 36 * cube_r + 6 * cube_g + cube_b;
+    let cube_distance = squared_distance(
+        r,
+        g,
+        b,
+        XTERM_CUBE_LEVELS[cube_r as usize],
+        XTERM_CUBE_LEVELS[cube_g as usize],
+        XTERM_CUBE_LEVELS[cube_b as usize],
+    );
+
+    let gray_avg = (r as u16 + g as u16 + b as u16) / 3;
+    let gray_step = gray_avg.saturating_sub(8) / 10;
+    let gray_step = gray_step.min(23);
+    let gray_level = 8 + 10 * gray_step;
+    let gray_index = 232 + gray_step as u8;
+    let gray_distance = squared_distance(r, g, b, gray_level, gray_level, gray_level);
+
+    if gray_distance < cube_distance {
+        gray_index
+    } else {
+        cube_index
+    }
+}
+
+/// Find the nearest of the 16 basic ANSI colors to an RGB triple by squared distance.
+fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16_PALETTE
+        .iter()
+        .min_by_key(|(_, pr, pg, pb)| squared_distance(r, g, b, *pr as u16, *pg as u16, *pb as u16))
+        .map(|(color, _, _, _)| *color)
+        .unwrap_or(Color::White)
+}
+
+/// Tracks nesting state for a single open list (`Tag::List`) so `Tag::Item` can render the
+/// right bullet or ordinal and indentation.
+struct ListContext {
+    /// `Some(n)` for an ordered list starting at `n`, `None` for an unordered list.
+    start_number: Option<u64>,
+    /// The ordinal to print for the next item, incremented as items are emitted.
+    item_index: u64,
+}
+
+/// Theme name used when the user asks for `--theme auto` (or sets no theme at all): resolved to
+/// a dark or light built-in theme based on [`detect_terminal_background_is_light`].
+const AUTO_THEME_NAME: &str = "auto";
+const DEFAULT_DARK_THEME: &str = "base16-ocean.dark";
+const DEFAULT_LIGHT_THEME: &str = "base16-ocean.light";
+
+/// Load any `.tmTheme` files dropped in the user's config directory (`mdless/themes/`) into
+/// `theme_set`, so users can add bat/Sublime themes without recompiling. Missing directories are
+/// silently ignored; malformed theme files are skipped rather than failing startup.
+fn load_user_themes(theme_set: &mut ThemeSet) {
+    let Some(config_dir) = dirs::config_dir() else {
+        return;
+    };
+    let themes_dir = config_dir.join("mdless").join("themes");
+    if !themes_dir.is_dir() {
+        return;
+    }
+    let Ok(paths) = ThemeSet::discover_theme_paths(&themes_dir) else {
+        return;
+    };
+    for path in paths {
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        if let Ok(theme) = ThemeSet::get_theme(&path) {
+            theme_set.themes.insert(name.to_string(), theme);
+        }
+    }
+}
+
+/// Guess whether the terminal has a light background from the `COLORFGBG` convention
+/// (`"fg;bg"`, xterm color indices 7 and above are light), used to resolve `--theme auto`.
+fn detect_terminal_background_is_light() -> bool {
+    env::var("COLORFGBG")
+        .ok()
+        .and_then(|colorfgbg| colorfgbg.rsplit(';').next().map(str::to_string))
+        .and_then(|bg| bg.parse::<u8>().ok())
+        .is_some_and(|bg_index| bg_index >= 7)
+}
+
+/// Flush the in-progress line, prefixing it with a blockquote gutter for each level of nesting
+/// currently open.
+fn flush_line(
+    lines: &mut Vec<Line<'static>>,
+    current_line: &mut Vec<Span<'static>>,
+    blockquote_depth: u8,
+) {
+    if current_line.is_empty() {
+        return;
+    }
+    let mut spans = Vec::new();
+    if blockquote_depth > 0 {
+        spans.push(Span::styled(
+            "▌ ".repeat(blockquote_depth as usize),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    spans.append(current_line);
+    lines.push(Line::from(spans));
+}
+
+/// A heading parsed out of the document, for building a jump-to table of contents.
+#[derive(Debug, Clone)]
+pub struct Heading {
+    pub level: u8,
+    pub text: String,
+    pub line_index: usize,
+}
+
+/// A link target parsed out of the document, for link-following navigation.
+#[derive(Debug, Clone)]
+pub struct LinkTarget {
+    pub url: String,
+    pub line_index: usize,
+}
+
 pub struct MarkdownRenderer {
     content: String,
     syntax_set: SyntaxSet,
     theme_set: ThemeSet,
+    tab_width: usize,
+    color_capability: ColorCapability,
+    theme_name: String,
+    line_numbers: bool,
 }
 
 impl MarkdownRenderer {
-    pub fn new() -> Self {
+    pub fn new(color_capability: ColorCapability, theme_name: &str, line_numbers: bool) -> Self {
+        let mut theme_set = ThemeSet::load_defaults();
+        load_user_themes(&mut theme_set);
+
+        let resolved_theme_name = if theme_name == AUTO_THEME_NAME {
+            if detect_terminal_background_is_light() {
+                DEFAULT_LIGHT_THEME
+            } else {
+                DEFAULT_DARK_THEME
+            }
+            .to_string()
+        } else if theme_set.themes.contains_key(theme_name) {
+            theme_name.to_string()
+        } else {
+            eprintln!(
+                "mdless: theme '{}' not found, falling back to '{}'",
+                theme_name, DEFAULT_DARK_THEME
+            );
+            DEFAULT_DARK_THEME.to_string()
+        };
+
         Self {
             content: String::new(),
             syntax_set: SyntaxSet::load_defaults_newlines(),
-            theme_set: ThemeSet::load_defaults(),
+            theme_set,
+            tab_width: DEFAULT_TAB_WIDTH,
+            color_capability,
+            theme_name: resolved_theme_name,
+            line_numbers,
+        }
+    }
+
+    /// List every syntax highlighting theme name available to `--theme`, including any
+    /// `.tmTheme` files dropped in the user's config directory.
+    pub fn list_theme_names() -> Vec<String> {
+        let mut theme_set = ThemeSet::load_defaults();
+        load_user_themes(&mut theme_set);
+        let mut names: Vec<String> = theme_set.themes.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Expand tabs to `tab_width` spaces so width measurement matches what a terminal displays;
+    /// syntect emits raw `\t` bytes for tab-indented code.
+    fn expand_tabs(&self, text: &str) -> String {
+        if !text.contains('\t') {
+            return text.to_string();
+        }
+
+        let mut expanded = String::with_capacity(text.len());
+        let mut column = 0usize;
+        for ch in text.chars() {
+            if ch == '\t' {
+                let spaces = self.tab_width - (column % self.tab_width);
+                expanded.push_str(&" ".repeat(spaces));
+                column += spaces;
+            } else {
+                expanded.push(ch);
+                column += UnicodeWidthChar::width(ch).unwrap_or(0);
+            }
+        }
+        expanded
+    }
+
+    /// Display width of `text` in terminal columns, tab-expanded first.
+    fn display_width(&self, text: &str) -> usize {
+        UnicodeWidthStr::width(self.expand_tabs(text).as_str())
+    }
+
+    /// Truncate `text` to at most `max_width` display columns, appending an ellipsis when
+    /// truncation actually happens so table cells never overflow their column.
+    fn truncate_to_width(&self, text: &str, max_width: usize) -> String {
+        if max_width == 0 {
+            return String::new();
+        }
+        if self.display_width(text) <= max_width {
+            return text.to_string();
+        }
+
+        let budget = max_width.saturating_sub(1); // leave room for the ellipsis
+        let mut truncated = String::new();
+        let mut width_so_far = 0usize;
+        for ch in text.chars() {
+            let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+            if width_so_far + ch_width > budget {
+                break;
+            }
+            truncated.push(ch);
+            width_so_far += ch_width;
+        }
+        truncated.push('…');
+        truncated
+    }
+
+    /// Append `text` to `current_line`, word-wrapping onto new lines (via `flush_line`) whenever
+    /// the next word would push the line past `available_width` display columns.
+    fn push_wrapped_text(
+        &self,
+        lines: &mut Vec<Line<'static>>,
+        current_line: &mut Vec<Span<'static>>,
+        text: &str,
+        style: Style,
+        available_width: usize,
+        blockquote_depth: u8,
+    ) {
+        for word in text.split_whitespace() {
+            let word_width = self.display_width(word);
+            let current_width: usize = current_line
+                .iter()
+                .map(|s| self.display_width(&s.content))
+                .sum();
+            // List and task markers already end in a space, so don't add a second one after them.
+            let needs_space = current_line.last().is_some_and(|span| {
+                !span.content.is_empty() && !span.content.ends_with(char::is_whitespace)
+            });
+            let projected_width = current_width + usize::from(needs_space) + word_width;
+
+            if projected_width > available_width && !current_line.is_empty() {
+                flush_line(lines, current_line, blockquote_depth);
+                current_line.push(Span::styled(word.to_string(), style));
+            } else {
+                if needs_space {
+                    current_line.push(Span::styled(" ", style));
+                }
+                current_line.push(Span::styled(word.to_string(), style));
+            }
         }
     }
 
@@ -30,8 +383,50 @@ impl MarkdownRenderer {
         Ok(())
     }
 
-    pub fn render_to_text(&self) -> Text<'static> {
-        let parser = Parser::new(&self.content);
+    /// The full text currently loaded, for callers (like the `--watch` streaming path) that need
+    /// to diff against it without re-reading the file themselves.
+    pub(crate) fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn render_to_text(&self, width: usize) -> Text<'static> {
+        Text::from(self.render_content_to_lines(&self.content, width).0)
+    }
+
+    /// Render a single, already-complete line of markdown in isolation, with no block state
+    /// (open lists/blockquotes/tables) carried over from anything before or after it. Used by the
+    /// `--watch` streaming path to flush a finished sentence-level chunk without re-rendering the
+    /// whole document, at the same `width` the rest of the document is currently wrapped to.
+    pub fn render_line_stateless(&self, line: &str, width: usize) -> Vec<Line<'static>> {
+        self.render_content_to_lines(line, width).0
+    }
+
+    /// The document's headings, with the line index they land on when rendered at `width`. Used
+    /// to build the outline navigation overlay.
+    pub fn headings(&self, width: usize) -> Vec<Heading> {
+        self.render_content_to_lines(&self.content, width).1
+    }
+
+    /// The document's link targets, with the line index they land on when rendered at `width`.
+    /// Used to build the link-following navigation.
+    pub fn links(&self, width: usize) -> Vec<LinkTarget> {
+        self.render_content_to_lines(&self.content, width).2
+    }
+
+    fn render_content_to_lines(
+        &self,
+        content: &str,
+        width: usize,
+    ) -> (Vec<Line<'static>>, Vec<Heading>, Vec<LinkTarget>) {
+        let width = width.clamp(MIN_RENDER_WIDTH, MAX_RENDER_WIDTH);
+
+        let parser = Parser::new_ext(
+            content,
+            Options::ENABLE_TABLES
+                | Options::ENABLE_STRIKETHROUGH
+                | Options::ENABLE_TASKLISTS
+                | Options::ENABLE_FOOTNOTES,
+        );
         let mut lines = Vec::new();
         let mut current_line = Vec::new();
         let mut in_code_block = false;
@@ -39,10 +434,25 @@ impl MarkdownRenderer {
         let mut code_block_content = String::new();
         let mut in_heading = false;
         let mut heading_level = 0;
+        let mut heading_text = String::new();
+        let mut headings: Vec<Heading> = Vec::new();
+        let mut links: Vec<LinkTarget> = Vec::new();
         let mut in_emphasis = false;
         let mut in_strong = false;
+        let mut in_strikethrough = false;
         let mut last_was_empty_line = true; // Track if the last line was empty
 
+        let mut list_stack: Vec<ListContext> = Vec::new();
+        let mut blockquote_depth: u8 = 0;
+
+        let mut in_table = false;
+        let mut table_alignments: Vec<Alignment> = Vec::new();
+        let mut table_header: Vec<String> = Vec::new();
+        let mut table_header_present = false;
+        let mut table_rows: Vec<Vec<String>> = Vec::new();
+        let mut current_row: Vec<String> = Vec::new();
+        let mut current_cell = String::new();
+
         for event in parser {
             match event {
                 Event::Start(tag) => {
@@ -50,10 +460,7 @@ impl MarkdownRenderer {
                         Tag::Heading { level, .. } => {
                             // If we have content in current_line or the last line wasn't empty,
                             // we need to add spacing before the heading
-                            if !current_line.is_empty() {
-                                lines.push(Line::from(current_line.clone()));
-                                current_line.clear();
-                            }
+                            flush_line(&mut lines, &mut current_line, blockquote_depth);
 
                             // Add blank line before heading if the last line wasn't already empty
                             if !last_was_empty_line && !lines.is_empty() {
@@ -62,6 +469,7 @@ impl MarkdownRenderer {
 
                             in_heading = true;
                             heading_level = level as u8;
+                            heading_text.clear();
                         }
                         Tag::CodeBlock(lang) => {
                             in_code_block = true;
@@ -72,10 +480,7 @@ impl MarkdownRenderer {
                                 }
                             };
                             code_block_content.clear();
-                            if !current_line.is_empty() {
-                                lines.push(Line::from(current_line.clone()));
-                                current_line.clear();
-                            }
+                            flush_line(&mut lines, &mut current_line, blockquote_depth);
                             // Add a blank line before code block for spacing
                             if !last_was_empty_line {
                                 lines.push(Line::from(""));
@@ -87,19 +492,88 @@ impl MarkdownRenderer {
                         Tag::Strong => {
                             in_strong = true;
                         }
+                        Tag::Strikethrough => {
+                            in_strikethrough = true;
+                        }
                         Tag::Paragraph => {
                             // Start new paragraph
                         }
+                        Tag::List(start_number) => {
+                            flush_line(&mut lines, &mut current_line, blockquote_depth);
+                            if !last_was_empty_line && list_stack.is_empty() {
+                                lines.push(Line::from(""));
+                            }
+                            list_stack.push(ListContext {
+                                start_number,
+                                item_index: start_number.unwrap_or(1),
+                            });
+                        }
+                        Tag::Item => {
+                            flush_line(&mut lines, &mut current_line, blockquote_depth);
+                            let depth = list_stack.len().saturating_sub(1);
+                            current_line.push(Span::styled(
+                                "  ".repeat(depth),
+                                Style::default(),
+                            ));
+
+                            let marker_text = if let Some(ctx) = list_stack.last_mut() {
+                                match ctx.start_number {
+                                    Some(_) => {
+                                        let marker = format!("{}. ", ctx.item_index);
+                                        ctx.item_index += 1;
+                                        marker
+                                    }
+                                    None => "• ".to_string(),
+                                }
+                            } else {
+                                "• ".to_string()
+                            };
+                            current_line
+                                .push(Span::styled(marker_text, Style::default().fg(Color::White)));
+                            last_was_empty_line = false;
+                        }
+                        Tag::BlockQuote(_) => {
+                            flush_line(&mut lines, &mut current_line, blockquote_depth);
+                            if !last_was_empty_line && blockquote_depth == 0 {
+                                lines.push(Line::from(""));
+                            }
+                            blockquote_depth += 1;
+                        }
+                        Tag::Table(alignments) => {
+                            flush_line(&mut lines, &mut current_line, blockquote_depth);
+                            if !last_was_empty_line {
+                                lines.push(Line::from(""));
+                            }
+                            in_table = true;
+                            table_alignments = alignments;
+                            table_header.clear();
+                            table_header_present = false;
+                            table_rows.clear();
+                        }
+                        Tag::TableHead | Tag::TableRow => {
+                            current_row.clear();
+                        }
+                        Tag::TableCell => {
+                            current_cell.clear();
+                        }
+                        Tag::Link { dest_url, .. } => {
+                            links.push(LinkTarget {
+                                url: dest_url.to_string(),
+                                line_index: lines.len(),
+                            });
+                        }
                         _ => {}
                     }
                 }
                 Event::End(tag_end) => match tag_end {
                     TagEnd::Heading(_) => {
                         in_heading = false;
-                        if !current_line.is_empty() {
-                            lines.push(Line::from(current_line.clone()));
-                            current_line.clear();
-                        }
+                        headings.push(Heading {
+                            level: heading_level,
+                            text: heading_text.clone(),
+                            line_index: lines.len(),
+                        });
+                        flush_line(&mut lines, &mut current_line, blockquote_depth);
                         lines.push(Line::from(""));
                         last_was_empty_line = true;
                     }
@@ -107,24 +581,27 @@ impl MarkdownRenderer {
                         in_code_block = false;
 
                         // Render the collected code block with syntax highlighting
-                        let highlighted_lines =
-                            self.highlight_code_block(&code_block_content, &code_block_language);
+                        let highlighted_lines = self.highlight_code_block(
+                            &code_block_content,
+                            &code_block_language,
+                            width,
+                        );
 
-                        // Add top border (79 characters wide)
+                        let horizontal_rule = "─".repeat(width.saturating_sub(2));
+
+                        // Add top border
                         lines.push(Line::from(vec![Span::styled(
-                            "┌─────────────────────────────────────────────────────────────────────────────┐",
-                            Style::default().fg(Color::DarkGray)
+                            format!("┌{}┐", horizontal_rule),
+                            Style::default().fg(Color::DarkGray),
                         )]));
 
                         // Add language label if present
                         if !code_block_language.is_empty() {
-                            // Calculate proper padding for language label
-                            // The border is 79 display characters wide
                             // Content structure: "│ " + language + padding + "│"
-                            // We want: 2 (for "│ ") + language_len + padding + 1 (for "│") = 79 chars
-                            let language_display_width = code_block_language.chars().count();
+                            // We want: 2 (for "│ ") + language_width + padding + 1 (for "│") = width
+                            let language_display_width = self.display_width(&code_block_language);
                             let padding_needed =
-                                79_usize.saturating_sub(2 + language_display_width + 1);
+                                width.saturating_sub(2 + language_display_width + 1);
 
                             lines.push(Line::from(vec![
                                 Span::styled("│ ", Style::default().fg(Color::DarkGray)),
@@ -138,8 +615,8 @@ impl MarkdownRenderer {
                                 Span::styled("│", Style::default().fg(Color::DarkGray)),
                             ]));
                             lines.push(Line::from(vec![Span::styled(
-                                "├─────────────────────────────────────────────────────────────────────────────┤",
-                                Style::default().fg(Color::DarkGray)
+                                format!("├{}┤", horizontal_rule),
+                                Style::default().fg(Color::DarkGray),
                             )]));
                         }
 
@@ -150,8 +627,8 @@ impl MarkdownRenderer {
 
                         // Add bottom border
                         lines.push(Line::from(vec![Span::styled(
-                            "└─────────────────────────────────────────────────────────────────────────────┘",
-                            Style::default().fg(Color::DarkGray)
+                            format!("└{}┘", horizontal_rule),
+                            Style::default().fg(Color::DarkGray),
                         )]));
 
                         lines.push(Line::from(""));
@@ -165,19 +642,66 @@ impl MarkdownRenderer {
                     TagEnd::Strong => {
                         in_strong = false;
                     }
+                    TagEnd::Strikethrough => {
+                        in_strikethrough = false;
+                    }
                     TagEnd::Paragraph => {
-                        if !current_line.is_empty() {
-                            lines.push(Line::from(current_line.clone()));
-                            current_line.clear();
+                        flush_line(&mut lines, &mut current_line, blockquote_depth);
+                        lines.push(Line::from(""));
+                        last_was_empty_line = true;
+                    }
+                    TagEnd::List(_) => {
+                        flush_line(&mut lines, &mut current_line, blockquote_depth);
+                        list_stack.pop();
+                        if list_stack.is_empty() {
+                            lines.push(Line::from(""));
+                            last_was_empty_line = true;
+                        }
+                    }
+                    TagEnd::Item => {
+                        flush_line(&mut lines, &mut current_line, blockquote_depth);
+                    }
+                    TagEnd::BlockQuote(_) => {
+                        flush_line(&mut lines, &mut current_line, blockquote_depth);
+                        blockquote_depth = blockquote_depth.saturating_sub(1);
+                        if blockquote_depth == 0 {
+                            lines.push(Line::from(""));
+                            last_was_empty_line = true;
                         }
+                    }
+                    TagEnd::TableHead => {
+                        table_header = current_row.clone();
+                        table_header_present = true;
+                        current_row.clear();
+                    }
+                    TagEnd::TableRow => {
+                        table_rows.push(current_row.clone());
+                        current_row.clear();
+                    }
+                    TagEnd::TableCell => {
+                        current_row.push(current_cell.clone());
+                        current_cell.clear();
+                    }
+                    TagEnd::Table => {
+                        in_table = false;
+                        let header_opt = table_header_present.then_some(table_header.as_slice());
+                        let table_lines =
+                            self.render_table(header_opt, &table_rows, &table_alignments, width);
+                        lines.extend(table_lines);
                         lines.push(Line::from(""));
                         last_was_empty_line = true;
+                        table_header.clear();
+                        table_header_present = false;
+                        table_rows.clear();
+                        table_alignments.clear();
                     }
                     _ => {}
                 },
                 Event::Text(text) => {
                     if in_code_block {
                         code_block_content.push_str(&text);
+                    } else if in_table {
+                        current_cell.push_str(&text);
                     } else {
                         let style = self.get_text_style(
                             in_heading,
@@ -185,9 +709,28 @@ impl MarkdownRenderer {
                             in_code_block,
                             in_emphasis,
                             in_strong,
+                            in_strikethrough,
                         );
 
-                        current_line.push(Span::styled(text.to_string(), style));
+                        if in_heading {
+                            // Headings read better as a single line; let them overflow rather
+                            // than wrap mid-title.
+                            heading_text.push_str(&text);
+                            current_line.push(Span::styled(text.to_string(), style));
+                        } else {
+                            let gutter_width = self.display_width(
+                                &"▌ ".repeat(blockquote_depth as usize),
+                            );
+                            let available_width = width.saturating_sub(gutter_width).max(1);
+                            self.push_wrapped_text(
+                                &mut lines,
+                                &mut current_line,
+                                &text,
+                                style,
+                                available_width,
+                                blockquote_depth,
+                            );
+                        }
                         // Text content means we're not on an empty line
                         if !text.trim().is_empty() {
                             last_was_empty_line = false;
@@ -195,17 +738,33 @@ impl MarkdownRenderer {
                     }
                 }
                 Event::Code(code) => {
-                    let style = Style::default()
-                        .fg(Color::Yellow)
-                        .bg(Color::Rgb(40, 40, 40))
-                        .add_modifier(Modifier::BOLD);
-                    current_line.push(Span::styled(format!(" {} ", code), style));
+                    if in_table {
+                        current_cell.push_str(&code);
+                    } else {
+                        let style = Style::default()
+                            .fg(Color::Yellow)
+                            .bg(Color::Rgb(40, 40, 40))
+                            .add_modifier(Modifier::BOLD);
+                        current_line.push(Span::styled(format!(" {} ", code), style));
+                        last_was_empty_line = false;
+                    }
+                }
+                Event::TaskListMarker(checked) => {
+                    // Replace the ordinary bullet/number marker pushed in `Tag::Item` with a
+                    // checkbox, since this item turned out to be a task-list item.
+                    if current_line.len() >= 2 {
+                        current_line.pop();
+                    }
+                    let marker = if checked { "☑ " } else { "☐ " };
+                    current_line.push(Span::styled(
+                        marker,
+                        Style::default().fg(Color::Green),
+                    ));
                     last_was_empty_line = false;
                 }
                 Event::SoftBreak | Event::HardBreak => {
                     if !current_line.is_empty() {
-                        lines.push(Line::from(current_line.clone()));
-                        current_line.clear();
+                        flush_line(&mut lines, &mut current_line, blockquote_depth);
                         last_was_empty_line = false;
                     }
                 }
@@ -213,14 +772,12 @@ impl MarkdownRenderer {
             }
         }
 
-        if !current_line.is_empty() {
-            lines.push(Line::from(current_line));
-        }
+        flush_line(&mut lines, &mut current_line, blockquote_depth);
 
-        Text::from(lines)
+        (lines, headings, links)
     }
 
-    fn highlight_code_block(&self, code: &str, language: &str) -> Vec<Line<'static>> {
+    fn highlight_code_block(&self, code: &str, language: &str, width: usize) -> Vec<Line<'static>> {
         let mut lines = Vec::new();
 
         // Try to find the syntax for the given language
@@ -233,45 +790,136 @@ impl MarkdownRenderer {
                 .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text())
         };
 
-        // Use a dark theme for better terminal compatibility
-        let theme = &self.theme_set.themes["base16-ocean.dark"];
+        // Fall back to the default dark theme if the resolved theme somehow isn't loaded.
+        let theme = self
+            .theme_set
+            .themes
+            .get(&self.theme_name)
+            .unwrap_or_else(|| &self.theme_set.themes[DEFAULT_DARK_THEME]);
         let mut highlighter = HighlightLines::new(syntax, theme);
 
-        for line in LinesWithEndings::from(code) {
+        // Numbers restart at 1 for each fenced block; width covers the largest number in it.
+        let total_lines = LinesWithEndings::from(code).count();
+        let gutter_width = total_lines.to_string().len().max(4);
+
+        let first_prefix = {
+            let mut spans = vec![Span::styled("│ ", Style::default().fg(Color::DarkGray))];
+            if self.line_numbers {
+                // The line number itself is filled in per-row below; reserve the slot here.
+                spans.push(Span::raw(""));
+                spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+            }
+            spans
+        };
+        let prefix_width = if self.line_numbers {
+            2 + gutter_width + 3
+        } else {
+            2
+        };
+
+        // Continuation rows (for source lines too long to fit) blank out the gutter and carry a
+        // subtle marker instead of a line number.
+        let continuation_prefix = if self.line_numbers {
+            vec![
+                Span::styled("│ ", Style::default().fg(Color::DarkGray)),
+                Span::styled(" ".repeat(gutter_width), Style::default().fg(Color::DarkGray)),
+                Span::styled(" ↪ ", Style::default().fg(Color::DarkGray)),
+            ]
+        } else {
+            vec![
+                Span::styled("│ ", Style::default().fg(Color::DarkGray)),
+                Span::styled("↪ ", Style::default().fg(Color::DarkGray)),
+            ]
+        };
+        let continuation_prefix_width: usize = continuation_prefix
+            .iter()
+            .map(|s| self.display_width(&s.content))
+            .sum();
+
+        let content_width = width.saturating_sub(prefix_width + 1).max(1);
+        let continuation_content_width = width.saturating_sub(continuation_prefix_width + 1).max(1);
+
+        for (index, line) in LinesWithEndings::from(code).enumerate() {
             let highlighted = highlighter
                 .highlight_line(line, &self.syntax_set)
                 .unwrap_or_else(|_| vec![(SyntectStyle::default(), line)]);
 
-            let mut spans = vec![Span::styled("│ ", Style::default().fg(Color::DarkGray))];
-
+            // Flatten into a (char, style) stream so long lines can be wrapped a character at a
+            // time without needing to re-split syntect's token spans.
+            let mut chars: Vec<(char, Style)> = Vec::new();
             for (style, text) in highlighted {
                 let ratatui_style = self.syntect_style_to_ratatui(style);
-                // Strip newlines from the text since they don't contribute to display width
-                let display_text = text.trim_end_matches('\n');
-                if !display_text.is_empty() {
-                    spans.push(Span::styled(display_text.to_string(), ratatui_style));
+                let display_text = self.expand_tabs(text.trim_end_matches('\n'));
+                for ch in display_text.chars() {
+                    chars.push((ch, ratatui_style));
                 }
             }
 
-            // Pad the line to fit within the border
-            // Target: "│ " + content + padding + "│" = 79 display characters
-            let content_length: usize = spans
-                .iter()
-                .skip(1)
-                .map(|s| s.content.chars().count())
-                .sum();
-            let padding_needed = 79_usize.saturating_sub(2 + content_length + 1);
-            spans.push(Span::styled(" ".repeat(padding_needed), Style::default()));
+            let mut pos = 0usize;
+            let mut is_first_row = true;
+            loop {
+                let (prefix, row_prefix_width, limit) = if is_first_row {
+                    (first_prefix.clone(), prefix_width, content_width)
+                } else {
+                    (
+                        continuation_prefix.clone(),
+                        continuation_prefix_width,
+                        continuation_content_width,
+                    )
+                };
+
+                let mut spans = prefix;
+                if is_first_row && self.line_numbers {
+                    let line_number = index + 1;
+                    spans[1] = Span::styled(
+                        format!("{:>width$}", line_number, width = gutter_width),
+                        Style::default()
+                            .fg(Color::DarkGray)
+                            .add_modifier(Modifier::DIM),
+                    );
+                }
 
-            spans.push(Span::styled("│", Style::default().fg(Color::DarkGray)));
-            lines.push(Line::from(spans));
+                let mut consumed_width = 0usize;
+                let mut run = String::new();
+                let mut run_style: Option<Style> = None;
+                let mut consumed_chars = 0usize;
+                for &(ch, style) in &chars[pos..] {
+                    let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+                    if consumed_width + ch_width > limit {
+                        break;
+                    }
+                    if run_style != Some(style) {
+                        if !run.is_empty() {
+                            spans.push(Span::styled(std::mem::take(&mut run), run_style.unwrap()));
+                        }
+                        run_style = Some(style);
+                    }
+                    run.push(ch);
+                    consumed_width += ch_width;
+                    consumed_chars += 1;
+                }
+                if !run.is_empty() {
+                    spans.push(Span::styled(run, run_style.unwrap()));
+                }
+                pos += consumed_chars;
+
+                let padding_needed = width.saturating_sub(row_prefix_width + consumed_width + 1);
+                spans.push(Span::styled(" ".repeat(padding_needed), Style::default()));
+                spans.push(Span::styled("│", Style::default().fg(Color::DarkGray)));
+                lines.push(Line::from(spans));
+
+                is_first_row = false;
+                if pos >= chars.len() {
+                    break;
+                }
+            }
         }
 
         // If no lines were added (empty code block), add an empty line
         if lines.is_empty() {
             lines.push(Line::from(vec![
                 Span::styled("│", Style::default().fg(Color::DarkGray)),
-                Span::styled(" ".repeat(76), Style::default()), // 79 - 2 - 1 = 76 spaces
+                Span::styled(" ".repeat(width.saturating_sub(2)), Style::default()),
                 Span::styled("│", Style::default().fg(Color::DarkGray)),
             ]));
         }
@@ -284,15 +932,15 @@ impl MarkdownRenderer {
 
         // Convert foreground color
         let fg_color = syntect_style.foreground;
-        style = style.fg(Color::Rgb(fg_color.r, fg_color.g, fg_color.b));
+        style = style.fg(self.downsample_color(fg_color.r, fg_color.g, fg_color.b));
 
         // Convert background color if it's not transparent
         if syntect_style.background.a > 0 {
             let bg_color = syntect_style.background;
-            style = style.bg(Color::Rgb(bg_color.r, bg_color.g, bg_color.b));
+            style = style.bg(self.downsample_color(bg_color.r, bg_color.g, bg_color.b));
         } else {
             // Use a dark background for code blocks
-            style = style.bg(Color::Rgb(30, 30, 30));
+            style = style.bg(self.downsample_color(30, 30, 30));
         }
 
         // Convert font style
@@ -318,6 +966,16 @@ impl MarkdownRenderer {
         style
     }
 
+    /// Convert a truecolor RGB triple to whatever [`ColorCapability`] the renderer was
+    /// configured with, so output stays legible on terminals that can't do 24-bit color.
+    fn downsample_color(&self, r: u8, g: u8, b: u8) -> Color {
+        match self.color_capability {
+            ColorCapability::Truecolor => Color::Rgb(r, g, b),
+            ColorCapability::Indexed256 => Color::Indexed(rgb_to_xterm256(r, g, b)),
+            ColorCapability::Ansi16 => rgb_to_ansi16(r, g, b),
+        }
+    }
+
     fn get_text_style(
         &self,
         in_heading: bool,
@@ -325,6 +983,7 @@ impl MarkdownRenderer {
         in_code_block: bool,
         in_emphasis: bool,
         in_strong: bool,
+        in_strikethrough: bool,
     ) -> Style {
         let mut style = Style::default();
 
@@ -351,8 +1010,114 @@ impl MarkdownRenderer {
             style = style.add_modifier(Modifier::BOLD);
         }
 
+        if in_strikethrough {
+            style = style.add_modifier(Modifier::CROSSED_OUT);
+        }
+
         style
     }
+
+    /// Render a table collected as header + body rows into a box-drawing grid, column widths
+    /// sized to the widest cell and alignment honored per `alignments`.
+    fn render_table(
+        &self,
+        header: Option<&[String]>,
+        rows: &[Vec<String>],
+        alignments: &[Alignment],
+        width: usize,
+    ) -> Vec<Line<'static>> {
+        let column_count = header
+            .map(|h| h.len())
+            .into_iter()
+            .chain(rows.iter().map(|r| r.len()))
+            .max()
+            .unwrap_or(0);
+
+        if column_count == 0 {
+            return Vec::new();
+        }
+
+        let mut column_widths = vec![3usize; column_count];
+        let mut measure_row = |row: &[String], column_widths: &mut [usize]| {
+            for (i, cell) in row.iter().enumerate() {
+                column_widths[i] = column_widths[i].max(self.display_width(cell));
+            }
+        };
+        if let Some(header) = header {
+            measure_row(header, &mut column_widths);
+        }
+        for row in rows {
+            measure_row(row, &mut column_widths);
+        }
+
+        // Shrink columns proportionally if the natural widths don't fit the terminal: each
+        // column costs its width plus " " padding on both sides plus one border character.
+        let border_overhead = column_count + 1 + column_count * 2;
+        let available_for_content = width.saturating_sub(border_overhead);
+        let total_content_width: usize = column_widths.iter().sum();
+        if total_content_width > available_for_content && available_for_content > 0 {
+            for column_width in column_widths.iter_mut() {
+                *column_width =
+                    ((*column_width * available_for_content) / total_content_width).max(3);
+            }
+        }
+
+        let border_style = Style::default().fg(Color::DarkGray);
+        let rule = |left: &str, mid: &str, right: &str| -> Line<'static> {
+            let mut spans = vec![Span::styled(left.to_string(), border_style)];
+            for (i, width) in column_widths.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::styled(mid.to_string(), border_style));
+                }
+                spans.push(Span::styled("─".repeat(width + 2), border_style));
+            }
+            spans.push(Span::styled(right.to_string(), border_style));
+            Line::from(spans)
+        };
+
+        let row_line = |row: &[String], bold: bool| -> Line<'static> {
+            let mut spans = vec![Span::styled("│".to_string(), border_style)];
+            for (i, width) in column_widths.iter().enumerate() {
+                let cell = row.get(i).map(String::as_str).unwrap_or("");
+                let cell = self.truncate_to_width(cell, *width);
+                let padding = width.saturating_sub(self.display_width(&cell));
+                let alignment = alignments.get(i).copied().unwrap_or(Alignment::None);
+                let (left_pad, right_pad) = match alignment {
+                    Alignment::Right => (padding, 0),
+                    Alignment::Center => (padding / 2, padding - padding / 2),
+                    Alignment::Left | Alignment::None => (0, padding),
+                };
+
+                let mut style = Style::default();
+                if bold {
+                    style = style.add_modifier(Modifier::BOLD);
+                }
+                spans.push(Span::styled(
+                    format!(
+                        " {}{}{} ",
+                        " ".repeat(left_pad),
+                        cell,
+                        " ".repeat(right_pad)
+                    ),
+                    style,
+                ));
+                spans.push(Span::styled("│".to_string(), border_style));
+            }
+            Line::from(spans)
+        };
+
+        let mut lines = vec![rule("┌", "┬", "┐")];
+        if let Some(header) = header {
+            lines.push(row_line(header, true));
+            lines.push(rule("├", "┼", "┤"));
+        }
+        for row in rows {
+            lines.push(row_line(row, false));
+        }
+        lines.push(rule("└", "┴", "┘"));
+
+        lines
+    }
 }
 
 #[cfg(test)]
@@ -361,35 +1126,63 @@ mod tests {
 
     #[test]
     fn test_new_renderer() {
-        let renderer = MarkdownRenderer::new();
+        let renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
         assert!(renderer.content.is_empty());
     }
 
     #[test]
     fn test_render_simple_text() {
-        let mut renderer = MarkdownRenderer::new();
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
         renderer.content = "Hello, world!".to_string();
 
-        let text = renderer.render_to_text();
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
         assert!(!text.lines.is_empty());
     }
 
     #[test]
     fn test_render_heading() {
-        let mut renderer = MarkdownRenderer::new();
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
         renderer.content = "# Main Title\n\nSome content".to_string();
 
-        let text = renderer.render_to_text();
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
         assert!(text.lines.len() >= 2);
     }
 
+    #[test]
+    fn test_headings_reports_level_text_and_line_index() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "# Title\n\nIntro text.\n\n## Section One".to_string();
+
+        let headings = renderer.headings(DEFAULT_RENDER_WIDTH);
+
+        assert_eq!(headings.len(), 2);
+        assert_eq!(headings[0].level, 1);
+        assert_eq!(headings[0].text, "Title");
+        assert_eq!(headings[1].level, 2);
+        assert_eq!(headings[1].text, "Section One");
+        assert!(headings[1].line_index > headings[0].line_index);
+    }
+
+    #[test]
+    fn test_links_reports_url_and_line_index() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "See [other doc](other.md) for more,\n\nand [again](./again.md).".to_string();
+
+        let links = renderer.links(DEFAULT_RENDER_WIDTH);
+
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].url, "other.md");
+        assert_eq!(links[1].url, "./again.md");
+        assert!(links[1].line_index > links[0].line_index);
+    }
+
     #[test]
     fn test_header_spacing_fix() {
-        let mut renderer = MarkdownRenderer::new();
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
         renderer.content =
             "Some paragraph text.\n### Header without spacing\nMore content.".to_string();
 
-        let text = renderer.render_to_text();
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
 
         // The rendered text should have proper spacing before the header
         // We expect: paragraph line, empty line, header line, empty line, content line
@@ -428,11 +1221,11 @@ mod tests {
 
     #[test]
     fn test_multiple_headers_without_spacing() {
-        let mut renderer = MarkdownRenderer::new();
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
         renderer.content =
             "Paragraph.\n### First Header\nContent.\n### Second Header\nMore content.".to_string();
 
-        let text = renderer.render_to_text();
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
 
         // Should have proper spacing before both headers
         let lines_text: Vec<String> = text
@@ -480,10 +1273,10 @@ mod tests {
 
     #[test]
     fn test_code_block_border_alignment() {
-        let mut renderer = MarkdownRenderer::new();
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
         renderer.content = "```rust\nfn main() {}\n```".to_string();
 
-        let text = renderer.render_to_text();
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
 
         // Find the language header line (contains "rust")
         let language_line_idx = text
@@ -494,17 +1287,17 @@ mod tests {
 
         let language_line = &text.lines[language_line_idx];
 
-        // Calculate the display width (character count, not byte count)
+        // Calculate the display width (terminal columns, not char count)
         let display_width: usize = language_line
             .spans
             .iter()
-            .map(|s| s.content.chars().count())
+            .map(|s| UnicodeWidthStr::width(s.content.as_ref()))
             .sum();
 
-        // The language header line should have exactly 79 display characters to match the border
+        // The language header line should have exactly 79 display columns to match the border
         assert_eq!(
             display_width, 79,
-            "Language line should be exactly 79 display characters wide"
+            "Language line should be exactly 79 display columns wide"
         );
 
         // Check that it has the proper structure
@@ -526,10 +1319,10 @@ mod tests {
 
     #[test]
     fn test_code_block_content_line_alignment() {
-        let mut renderer = MarkdownRenderer::new();
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
         renderer.content = "```rust\nfn main() {\n    println!(\"Hello, world!\");\n}\n```".to_string();
 
-        let text = renderer.render_to_text();
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
 
         // Find all lines that contain code content (between borders)
         let code_content_lines: Vec<&Line> = text
@@ -545,17 +1338,373 @@ mod tests {
             })
             .collect();
 
-        // Each code content line should have exactly 79 display characters
+        // Each code content line should have exactly 79 display columns
+        for line in code_content_lines {
+            let display_width: usize = line
+                .spans
+                .iter()
+                .map(|s| UnicodeWidthStr::width(s.content.as_ref()))
+                .sum();
+
+            assert_eq!(
+                display_width, 79,
+                "Code content line should be exactly 79 display columns wide"
+            );
+        }
+    }
+
+    #[test]
+    fn test_code_block_width_follows_terminal_width() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "```rust\nfn main() {}\n```".to_string();
+
+        for width in [MIN_RENDER_WIDTH, 60, MAX_RENDER_WIDTH] {
+            let text = renderer.render_to_text(width);
+            let border_line = text
+                .lines
+                .iter()
+                .find(|line| line.spans.iter().any(|span| span.content.contains("┌")))
+                .expect("should find a top border line");
+
+            let display_width: usize = border_line
+                .spans
+                .iter()
+                .map(|s| UnicodeWidthStr::width(s.content.as_ref()))
+                .sum();
+            assert_eq!(
+                display_width, width,
+                "Top border should match the requested render width"
+            );
+        }
+    }
+
+    #[test]
+    fn test_code_block_soft_wraps_long_lines() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = format!("```rust\n{}\n```", "x".repeat(100));
+
+        let text = renderer.render_to_text(MIN_RENDER_WIDTH);
+
+        let code_content_lines: Vec<&Line> = text
+            .lines
+            .iter()
+            .filter(|line| {
+                line.spans.len() >= 2
+                    && line.spans[0].content == "│ "
+                    && line.spans.last().unwrap().content == "│"
+                    && !line.spans.iter().any(|span| span.content.contains("─"))
+                    && !line.spans.iter().any(|span| span.content.contains("rust"))
+            })
+            .collect();
+
+        // A 100-char line doesn't fit in a 40-column block, so it should soft-wrap onto more
+        // than one row, each still exactly MIN_RENDER_WIDTH columns wide.
+        assert!(
+            code_content_lines.len() > 1,
+            "A long code line should wrap onto continuation rows"
+        );
+        assert!(
+            code_content_lines
+                .iter()
+                .skip(1)
+                .all(|line| line.spans.iter().any(|span| span.content.contains("↪"))),
+            "Continuation rows should carry the wrap marker"
+        );
+        for line in &code_content_lines {
+            let display_width: usize = line
+                .spans
+                .iter()
+                .map(|s| UnicodeWidthStr::width(s.content.as_ref()))
+                .sum();
+            assert_eq!(display_width, MIN_RENDER_WIDTH);
+        }
+    }
+
+    #[test]
+    fn test_line_number_gutter() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", true);
+        renderer.content = "```rust\nfn main() {\n    let x = 1;\n}\n```".to_string();
+
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
+
+        let code_content_lines: Vec<&Line> = text
+            .lines
+            .iter()
+            .filter(|line| {
+                line.spans.len() >= 2
+                    && line.spans[0].content == "│ "
+                    && line.spans.last().unwrap().content == "│"
+                    && !line.spans.iter().any(|span| span.content.contains("─"))
+                    && !line.spans.iter().any(|span| span.content.contains("rust"))
+            })
+            .collect();
+
+        assert_eq!(code_content_lines.len(), 3);
+
+        let gutter_numbers: Vec<String> = code_content_lines
+            .iter()
+            .map(|line| line.spans[1].content.trim().to_string())
+            .collect();
+        assert_eq!(gutter_numbers, vec!["1", "2", "3"]);
+
+        for line in &code_content_lines {
+            let display_width: usize = line
+                .spans
+                .iter()
+                .map(|s| UnicodeWidthStr::width(s.content.as_ref()))
+                .sum();
+
+            assert_eq!(
+                display_width, 79,
+                "Code content line with a line-number gutter should still be 79 display columns wide"
+            );
+        }
+    }
+
+    #[test]
+    fn test_code_block_border_alignment_with_cjk() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "```rust\nlet 你好 = \"世界\"; // emoji: 🎉\n```".to_string();
+
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
+
+        let code_content_lines: Vec<&Line> = text
+            .lines
+            .iter()
+            .filter(|line| {
+                line.spans.len() >= 2
+                    && line.spans[0].content == "│ "
+                    && line.spans.last().unwrap().content == "│"
+                    && !line.spans.iter().any(|span| span.content.contains("─"))
+                    && !line.spans.iter().any(|span| span.content.contains("rust"))
+            })
+            .collect();
+
+        assert!(
+            !code_content_lines.is_empty(),
+            "Should find the CJK/emoji code line"
+        );
+
+        for line in code_content_lines {
+            let display_width: usize = line
+                .spans
+                .iter()
+                .map(|s| UnicodeWidthStr::width(s.content.as_ref()))
+                .sum();
+
+            assert_eq!(
+                display_width, 79,
+                "Code content line with wide characters should still be 79 display columns wide"
+            );
+        }
+    }
+
+    #[test]
+    fn test_tab_expansion_in_code_block() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "```rust\n\tfn main() {}\n```".to_string();
+
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
+
+        let code_content_lines: Vec<&Line> = text
+            .lines
+            .iter()
+            .filter(|line| {
+                line.spans.len() >= 2
+                    && line.spans[0].content == "│ "
+                    && line.spans.last().unwrap().content == "│"
+                    && !line.spans.iter().any(|span| span.content.contains("─"))
+                    && !line.spans.iter().any(|span| span.content.contains("rust"))
+            })
+            .collect();
+
         for line in code_content_lines {
             let display_width: usize = line
                 .spans
                 .iter()
-                .map(|s| s.content.chars().count())
+                .map(|s| UnicodeWidthStr::width(s.content.as_ref()))
                 .sum();
 
             assert_eq!(
                 display_width, 79,
-                "Code content line should be exactly 79 display characters wide"
+                "Tab-indented code line should still be 79 display columns wide"
+            );
+        }
+    }
+
+    fn line_text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_unordered_list_markers() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "- first\n- second\n- third".to_string();
+
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
+        let bullet_lines: Vec<String> = text
+            .lines
+            .iter()
+            .map(line_text)
+            .filter(|l| l.contains("first") || l.contains("second") || l.contains("third"))
+            .collect();
+
+        assert_eq!(bullet_lines.len(), 3);
+        assert!(bullet_lines[0].starts_with("• first"));
+        assert!(bullet_lines[1].starts_with("• second"));
+        assert!(bullet_lines[2].starts_with("• third"));
+    }
+
+    #[test]
+    fn test_ordered_list_markers() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "1. first\n2. second\n3. third".to_string();
+
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
+        let numbered_lines: Vec<String> = text
+            .lines
+            .iter()
+            .map(line_text)
+            .filter(|l| l.contains("first") || l.contains("second") || l.contains("third"))
+            .collect();
+
+        assert_eq!(numbered_lines.len(), 3);
+        assert!(numbered_lines[0].starts_with("1. first"));
+        assert!(numbered_lines[1].starts_with("2. second"));
+        assert!(numbered_lines[2].starts_with("3. third"));
+    }
+
+    #[test]
+    fn test_task_list_markers() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "- [ ] todo\n- [x] done".to_string();
+
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
+        let task_lines: Vec<String> = text
+            .lines
+            .iter()
+            .map(line_text)
+            .filter(|l| l.contains("todo") || l.contains("done"))
+            .collect();
+
+        assert_eq!(task_lines.len(), 2);
+        assert!(task_lines[0].starts_with("☐ todo"));
+        assert!(task_lines[1].starts_with("☑ done"));
+    }
+
+    #[test]
+    fn test_blockquote_gutter() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "> quoted text".to_string();
+
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
+        let quoted_line = text
+            .lines
+            .iter()
+            .map(line_text)
+            .find(|l| l.contains("quoted text"))
+            .expect("Should find the quoted line");
+
+        assert!(quoted_line.starts_with("▌ "));
+    }
+
+    #[test]
+    fn test_strikethrough_modifier() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "~~gone~~".to_string();
+
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
+        let span = text
+            .lines
+            .iter()
+            .flat_map(|line| line.spans.iter())
+            .find(|span| span.content.contains("gone"))
+            .expect("Should find the strikethrough span");
+
+        assert!(span.style.add_modifier.contains(Modifier::CROSSED_OUT));
+    }
+
+    #[test]
+    fn test_table_column_alignment() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content =
+            "| Name | Count |\n| :--- | ----: |\n| a | 1 |\n| bb | 22 |".to_string();
+
+        let text = renderer.render_to_text(DEFAULT_RENDER_WIDTH);
+        let table_lines: Vec<String> = text
+            .lines
+            .iter()
+            .map(line_text)
+            .filter(|l| l.starts_with('│') || l.starts_with('┌') || l.starts_with('├'))
+            .collect();
+
+        assert!(!table_lines.is_empty(), "Should render a table grid");
+
+        // All rendered rows (header, separator, body) should share the same total width.
+        let widths: Vec<usize> = table_lines
+            .iter()
+            .map(|l| UnicodeWidthStr::width(l.as_str()))
+            .collect();
+        let first_width = widths[0];
+        assert!(
+            widths.iter().all(|w| *w == first_width),
+            "All table rows should be the same display width: {:?}",
+            widths
+        );
+
+        // The right-aligned "Count" column should pad "1" on the left, not the right.
+        let count_row = table_lines
+            .iter()
+            .find(|l| l.contains('1') && !l.contains("22"))
+            .expect("Should find the row for count=1");
+        let count_cell = count_row
+            .trim_end_matches('│')
+            .rsplit('│')
+            .next()
+            .expect("Row should have a count cell");
+        assert!(
+            count_cell.ends_with("1 "),
+            "Right-aligned cell should pad on the left: {:?}",
+            count_cell
+        );
+        assert!(
+            !count_cell.starts_with(" 1"),
+            "Right-aligned cell should not pad on the right: {:?}",
+            count_cell
+        );
+    }
+
+    #[test]
+    fn test_paragraph_wraps_at_render_width() {
+        let mut renderer = MarkdownRenderer::new(ColorCapability::Truecolor, "base16-ocean.dark", false);
+        renderer.content = "one two three four five six seven eight nine ten".to_string();
+
+        let text = renderer.render_to_text(MIN_RENDER_WIDTH);
+
+        let lines_text: Vec<String> = text
+            .lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+
+        assert!(
+            lines_text.len() > 1,
+            "A long paragraph should wrap onto more than one line"
+        );
+        for line in &lines_text {
+            assert!(
+                UnicodeWidthStr::width(line.as_str()) <= MIN_RENDER_WIDTH,
+                "Wrapped line should not exceed the render width: {:?}",
+                line
             );
         }
     }
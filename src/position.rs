@@ -0,0 +1,155 @@
+// Copyright 2025 Ray Krueger <raykrueger@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// A saved reading position for a single file: scroll offset and the active search query, if
+/// there was one, so the next launch can resume exactly where the reader left off.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SavedPosition {
+    pub scroll_offset: u16,
+    pub query: String,
+}
+
+/// Look up the saved position for `file_path`, if the state file exists and holds an entry for
+/// it. Returns `None` (rather than erroring) if there's no saved state, matching `load_user_themes`.
+pub fn load(file_path: &Path) -> Option<SavedPosition> {
+    let contents = std::fs::read_to_string(state_file_path()?).ok()?;
+    let key = canonical_key(file_path);
+    contents
+        .lines()
+        .filter_map(parse_entry)
+        .find(|(line_key, _)| *line_key == key)
+        .map(|(_, saved)| saved)
+}
+
+/// Persist `position` for `file_path`, replacing any prior entry for the same file and leaving
+/// entries for other files untouched. Missing data directories are created; any I/O failure is
+/// silently ignored, since losing the resume point should never block quitting.
+pub fn save(file_path: &Path, position: &SavedPosition) {
+    let Some(state_path) = state_file_path() else {
+        return;
+    };
+    let key = canonical_key(file_path);
+
+    let mut entries: HashMap<String, SavedPosition> = std::fs::read_to_string(&state_path)
+        .ok()
+        .map(|contents| contents.lines().filter_map(parse_entry).collect())
+        .unwrap_or_default();
+    entries.insert(key, position.clone());
+
+    if let Some(parent) = state_path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+
+    let serialized: String = entries
+        .iter()
+        .map(|(key, saved)| format!("{}\t{}\t{}\n", key, saved.scroll_offset, saved.query))
+        .collect();
+    let _ = std::fs::write(&state_path, serialized);
+}
+
+/// Resolve `file_path` to the absolute path it's keyed under, so the same file opened via
+/// different relative paths resumes from the same saved position.
+fn canonical_key(file_path: &Path) -> String {
+    file_path
+        .canonicalize()
+        .unwrap_or_else(|_| file_path.to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Parse one `path\tscroll_offset\tquery` state-file line. Malformed lines are skipped rather
+/// than failing the whole load.
+fn parse_entry(line: &str) -> Option<(String, SavedPosition)> {
+    let mut parts = line.splitn(3, '\t');
+    let key = parts.next()?.to_string();
+    let scroll_offset: u16 = parts.next()?.parse().ok()?;
+    let query = parts.next().unwrap_or("").to_string();
+    Some((
+        key,
+        SavedPosition {
+            scroll_offset,
+            query,
+        },
+    ))
+}
+
+fn state_file_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("mdless").join("positions.tsv"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entry_roundtrips_with_serialized_line() {
+        let saved = SavedPosition {
+            scroll_offset: 42,
+            query: "needle".to_string(),
+        };
+        let line = format!("/tmp/doc.md\t{}\t{}", saved.scroll_offset, saved.query);
+
+        let (key, parsed) = parse_entry(&line).unwrap();
+        assert_eq!(key, "/tmp/doc.md");
+        assert_eq!(parsed, saved);
+    }
+
+    #[test]
+    fn test_parse_entry_defaults_query_when_missing() {
+        let (key, parsed) = parse_entry("/tmp/doc.md\t7").unwrap();
+        assert_eq!(key, "/tmp/doc.md");
+        assert_eq!(parsed.scroll_offset, 7);
+        assert_eq!(parsed.query, "");
+    }
+
+    #[test]
+    fn test_parse_entry_rejects_malformed_line() {
+        assert!(parse_entry("not enough fields").is_none());
+        assert!(parse_entry("/tmp/doc.md\tnot-a-number").is_none());
+    }
+
+    #[test]
+    fn test_load_save_round_trip_picks_the_right_file_among_several() {
+        let data_dir = tempfile::tempdir().unwrap();
+        // `dirs::data_dir` honors `XDG_DATA_HOME` on Linux, letting the test redirect the state
+        // file without touching the real one.
+        unsafe {
+            std::env::set_var("XDG_DATA_HOME", data_dir.path());
+        }
+
+        let file_a = PathBuf::from("/tmp/mdless-test-does-not-exist-a.md");
+        let file_b = PathBuf::from("/tmp/mdless-test-does-not-exist-b.md");
+        let saved_a = SavedPosition {
+            scroll_offset: 3,
+            query: "alpha".to_string(),
+        };
+        let saved_b = SavedPosition {
+            scroll_offset: 99,
+            query: "beta".to_string(),
+        };
+
+        save(&file_a, &saved_a);
+        save(&file_b, &saved_b);
+
+        assert_eq!(load(&file_a), Some(saved_a));
+        assert_eq!(load(&file_b), Some(saved_b));
+
+        unsafe {
+            std::env::remove_var("XDG_DATA_HOME");
+        }
+    }
+}
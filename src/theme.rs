@@ -0,0 +1,231 @@
+// Copyright 2025 Ray Krueger <raykrueger@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ratatui::style::Color;
+use std::path::PathBuf;
+
+/// Colors for the UI chrome (borders, titles, search state, footer), as opposed to the syntax
+/// highlighting theme (`--theme`) applied inside rendered code blocks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UiTheme {
+    pub name: &'static str,
+    pub header_title: Color,
+    pub header_text: Color,
+    pub border: Color,
+    pub content_fg: Color,
+    pub search_match: Color,
+    pub search_nomatch: Color,
+    pub footer_title: Color,
+    pub help_text: Color,
+    pub scrollbar: Color,
+}
+
+/// The original fixed palette this viewer used before themes existed, kept as the default.
+pub const DARK: UiTheme = UiTheme {
+    name: "dark",
+    header_title: Color::Cyan,
+    header_text: Color::White,
+    border: Color::White,
+    content_fg: Color::White,
+    search_match: Color::Yellow,
+    search_nomatch: Color::Red,
+    footer_title: Color::Green,
+    help_text: Color::Gray,
+    scrollbar: Color::White,
+};
+
+/// Modeled on rustdoc's "light" theme: dark text on a light background.
+pub const LIGHT: UiTheme = UiTheme {
+    name: "light",
+    header_title: Color::Rgb(0x38, 0x73, 0xad),
+    header_text: Color::Black,
+    border: Color::Black,
+    content_fg: Color::Black,
+    search_match: Color::Rgb(0xc2, 0x88, 0x17),
+    search_nomatch: Color::Rgb(0xc0, 0x30, 0x30),
+    footer_title: Color::Rgb(0x00, 0x6d, 0x00),
+    help_text: Color::DarkGray,
+    scrollbar: Color::DarkGray,
+};
+
+/// Modeled on rustdoc's "ayu" theme: warm accents on a cool dark background.
+pub const AYU: UiTheme = UiTheme {
+    name: "ayu",
+    header_title: Color::Rgb(0xff, 0xb4, 0x54),
+    header_text: Color::Rgb(0xe6, 0xe1, 0xcf),
+    border: Color::Rgb(0x5c, 0x67, 0x73),
+    content_fg: Color::Rgb(0xe6, 0xe1, 0xcf),
+    search_match: Color::Rgb(0xff, 0xb4, 0x54),
+    search_nomatch: Color::Rgb(0xf0, 0x71, 0x78),
+    footer_title: Color::Rgb(0x39, 0xba, 0xe6),
+    help_text: Color::Rgb(0x5c, 0x67, 0x73),
+    scrollbar: Color::Rgb(0x5c, 0x67, 0x73),
+};
+
+/// Every built-in theme, in the order the picker overlay lists them.
+pub const ALL: &[UiTheme] = &[DARK, LIGHT, AYU];
+
+/// Look up a built-in theme by name (case-insensitive), for resolving a persisted selection.
+pub fn by_name(name: &str) -> Option<UiTheme> {
+    ALL.iter()
+        .copied()
+        .find(|theme| theme.name.eq_ignore_ascii_case(name))
+}
+
+/// Load the persisted UI theme selection, if any, from the user's data directory. Falls back to
+/// `None` (and the caller's default) on a missing file, unreadable file, or unrecognized name.
+pub fn load_selected() -> Option<UiTheme> {
+    let contents = std::fs::read_to_string(state_file_path()?).ok()?;
+    by_name(contents.trim())
+}
+
+/// Persist `theme`'s name as the selected UI theme. Missing data directories are created; I/O
+/// failures are silently ignored, matching `position::save`.
+pub fn save_selected(theme: &UiTheme) {
+    let Some(state_path) = state_file_path() else {
+        return;
+    };
+    if let Some(parent) = state_path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let _ = std::fs::write(state_path, theme.name);
+}
+
+fn state_file_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("mdless").join("ui_theme.txt"))
+}
+
+/// Parse a user-supplied color value: a `#rrggbb` hex triplet, or one of the named terminal
+/// colors recognized by `ratatui::style::Color` (case-insensitive). Returns an error message
+/// naming the bad input instead of a `Color`, so the caller can report it and fall back to the
+/// theme's existing entry.
+pub fn parse_color(value: &str) -> std::result::Result<Color, String> {
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() == 6
+            && let Ok(rgb) = u32::from_str_radix(hex, 16)
+        {
+            return Ok(Color::Rgb(
+                ((rgb >> 16) & 0xff) as u8,
+                ((rgb >> 8) & 0xff) as u8,
+                (rgb & 0xff) as u8,
+            ));
+        }
+        return Err(format!("invalid hex color '{}'", value));
+    }
+
+    match value.to_ascii_lowercase().as_str() {
+        "black" => Ok(Color::Black),
+        "red" => Ok(Color::Red),
+        "green" => Ok(Color::Green),
+        "yellow" => Ok(Color::Yellow),
+        "blue" => Ok(Color::Blue),
+        "magenta" => Ok(Color::Magenta),
+        "cyan" => Ok(Color::Cyan),
+        "gray" | "grey" => Ok(Color::Gray),
+        "darkgray" | "darkgrey" | "dark_gray" => Ok(Color::DarkGray),
+        "lightred" | "light_red" => Ok(Color::LightRed),
+        "lightgreen" | "light_green" => Ok(Color::LightGreen),
+        "lightyellow" | "light_yellow" => Ok(Color::LightYellow),
+        "lightblue" | "light_blue" => Ok(Color::LightBlue),
+        "lightmagenta" | "light_magenta" => Ok(Color::LightMagenta),
+        "lightcyan" | "light_cyan" => Ok(Color::LightCyan),
+        "white" => Ok(Color::White),
+        _ => Err(format!("unrecognized color '{}'", value)),
+    }
+}
+
+/// Apply a single `field = color` override onto `theme`, used for both `colors.toml` entries and
+/// `--ui-color` CLI flags. Leaves `theme` untouched and returns an error message if `field` isn't
+/// a recognized color slot or `value` doesn't parse.
+pub fn apply_color_override(
+    theme: &mut UiTheme,
+    field: &str,
+    value: &str,
+) -> std::result::Result<(), String> {
+    let color = parse_color(value)?;
+    match field {
+        "header_title" => theme.header_title = color,
+        "header_text" => theme.header_text = color,
+        "border" => theme.border = color,
+        "content_fg" => theme.content_fg = color,
+        "search_match" => theme.search_match = color,
+        "search_nomatch" => theme.search_nomatch = color,
+        "footer_title" => theme.footer_title = color,
+        "help_text" => theme.help_text = color,
+        "scrollbar" => theme.scrollbar = color,
+        _ => return Err(format!("unknown color field '{}'", field)),
+    }
+    Ok(())
+}
+
+/// Load `field = "color"` override lines from `mdless/colors.toml` in the user's config dir, if
+/// present. A missing file yields no overrides; blank lines, `#` comments, and lines without `=`
+/// are skipped, same leniency as `KeyBindings::apply_overrides`.
+pub fn load_color_overrides() -> Vec<(String, String)> {
+    let Some(config_dir) = dirs::config_dir() else {
+        return Vec::new();
+    };
+    let path = config_dir.join("mdless").join("colors.toml");
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(field, value)| {
+            (
+                field.trim().trim_matches('"').to_string(),
+                value.trim().trim_matches('"').to_string(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_by_name_is_case_insensitive() {
+        assert_eq!(by_name("Dark"), Some(DARK));
+        assert_eq!(by_name("AYU"), Some(AYU));
+        assert_eq!(by_name("nope"), None);
+    }
+
+    #[test]
+    fn test_parse_color_hex_and_named() {
+        assert_eq!(parse_color("#ff0000"), Ok(Color::Rgb(0xff, 0, 0)));
+        assert_eq!(parse_color("Cyan"), Ok(Color::Cyan));
+        assert_eq!(parse_color("dark_gray"), Ok(Color::DarkGray));
+        assert!(parse_color("#zz0000").is_err());
+        assert!(parse_color("not-a-color").is_err());
+    }
+
+    #[test]
+    fn test_apply_color_override_sets_field_and_rejects_unknown() {
+        let mut theme = DARK;
+        apply_color_override(&mut theme, "search_match", "#112233").unwrap();
+        assert_eq!(theme.search_match, Color::Rgb(0x11, 0x22, 0x33));
+
+        let before = theme;
+        assert!(apply_color_override(&mut theme, "nonexistent_field", "red").is_err());
+        assert_eq!(theme, before, "a bad field name must leave the theme untouched");
+
+        assert!(apply_color_override(&mut theme, "border", "not-a-color").is_err());
+        assert_eq!(theme, before, "a bad color value must leave the theme untouched");
+    }
+}
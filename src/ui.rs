@@ -16,11 +16,16 @@ use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    text::{Line, Span, Text},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState,
+    },
 };
+use std::collections::HashMap;
 
-use crate::app::{App, AppMode};
+use crate::app::{App, AppMode, SearchState};
+use crate::theme::{self, UiTheme};
 
 pub fn draw(frame: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
@@ -32,36 +37,82 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         ])
         .split(frame.area());
 
-    draw_header(frame, chunks[0], app);
-    draw_content(frame, chunks[1], app);
+    let theme = *app.get_ui_theme();
+
+    draw_header(frame, chunks[0], app, &theme);
+
+    if app.outline_pane_visible() {
+        let content_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(28), Constraint::Min(0)])
+            .split(chunks[1]);
+        draw_outline_pane(frame, content_chunks[0], app, &theme);
+        draw_content(frame, content_chunks[1], app, &theme);
+    } else {
+        draw_content(frame, chunks[1], app, &theme);
+    }
 
     match app.get_mode() {
-        AppMode::Search => draw_search_bar(frame, chunks[2], app),
-        AppMode::Normal => draw_footer(frame, chunks[2], app),
+        AppMode::Search => draw_search_bar(frame, chunks[2], app, &theme),
+        AppMode::Mark => {
+            draw_mark_jump_bar(frame, chunks[2], "Mark", "press a letter to mark this spot")
+        }
+        AppMode::Jump => {
+            draw_mark_jump_bar(frame, chunks[2], "Jump", "press a letter to jump to its mark")
+        }
+        AppMode::Normal => draw_footer(frame, chunks[2], app, &theme),
+        AppMode::Outline => {}
+        AppMode::Info => draw_footer(frame, chunks[2], app, &theme),
+        AppMode::Link => draw_link_bar(frame, chunks[2], app),
+        AppMode::ThemePicker => {}
+    }
+
+    // The sidebar already shows the outline and takes selection input (see `draw_outline_pane`),
+    // so the centered overlay is only needed when the user hasn't toggled the pane on.
+    if *app.get_mode() == AppMode::Outline && !app.outline_pane_visible() {
+        draw_outline(frame, frame.area(), app);
+    }
+
+    if *app.get_mode() == AppMode::Info {
+        draw_info(frame, frame.area(), app);
+    }
+
+    if *app.get_mode() == AppMode::ThemePicker {
+        draw_theme_picker(frame, frame.area(), app);
     }
 }
 
-fn draw_header(frame: &mut Frame, area: Rect, app: &App) {
+fn draw_header(frame: &mut Frame, area: Rect, app: &App, theme: &UiTheme) {
     let title = format!("mdless - {}", app.get_file_name());
     let header = Paragraph::new(title)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title("Markdown Viewer")
-                .title_style(Style::default().fg(Color::Cyan)),
+                .title_style(Style::default().fg(theme.header_title)),
         )
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme.header_text));
 
     frame.render_widget(header, area);
 }
 
-fn draw_content(frame: &mut Frame, area: Rect, app: &mut App) {
-    let content = app.get_rendered_content().clone();
+fn draw_content(frame: &mut Frame, area: Rect, app: &mut App, theme: &UiTheme) {
+    // Borders consume two columns/rows; the renderer wraps content to the rest, clamped to its
+    // minimum and maximum render width.
+    app.set_content_width(area.width.saturating_sub(2));
+    app.set_viewport_height(area.height.saturating_sub(2));
+    app.set_content_area_top(area.y);
+    let mut content = app.get_rendered_content().clone();
+    highlight_search_matches(&mut content, app.get_search_state(), theme);
 
     let paragraph = Paragraph::new(content)
-        .block(Block::default().borders(Borders::ALL))
-        .scroll((app.get_scroll_offset(), 0))
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.border)),
+        )
+        .style(Style::default().fg(theme.content_fg))
+        .scroll((app.get_scroll_offset(), 0));
 
     frame.render_widget(paragraph, area);
 
@@ -69,7 +120,8 @@ fn draw_content(frame: &mut Frame, area: Rect, app: &mut App) {
     let scrollbar = Scrollbar::default()
         .orientation(ScrollbarOrientation::VerticalRight)
         .begin_symbol(Some("↑"))
-        .end_symbol(Some("↓"));
+        .end_symbol(Some("↓"))
+        .style(Style::default().fg(theme.scrollbar));
 
     let mut scrollbar_state = ScrollbarState::default()
         .content_length(app.get_content_length() as usize)
@@ -85,26 +137,124 @@ fn draw_content(frame: &mut Frame, area: Rect, app: &mut App) {
     );
 }
 
-fn draw_search_bar(frame: &mut Frame, area: Rect, app: &App) {
+/// Overlay every active search match onto `content` with a colored background, distinguishing
+/// the current result (bold reverse-video in the theme's match color) from the rest (dark gray).
+fn highlight_search_matches(content: &mut Text<'static>, search_state: &SearchState, theme: &UiTheme) {
+    if search_state.results.is_empty() {
+        return;
+    }
+
+    let mut matches_by_line: HashMap<usize, Vec<(usize, usize, bool)>> = HashMap::new();
+    for (index, result) in search_state.results.iter().enumerate() {
+        let is_current = search_state.current_result_index == Some(index);
+        matches_by_line
+            .entry(result.line_index)
+            .or_default()
+            .push((result.char_start, result.char_end, is_current));
+    }
+
+    for (line_index, ranges) in matches_by_line {
+        if let Some(line) = content.lines.get_mut(line_index) {
+            *line = highlight_line_matches(line, &ranges, theme);
+        }
+    }
+}
+
+/// Rebuild `line`'s spans, splitting them at each match boundary and overriding the background
+/// of matched ranges so matches stay visible regardless of the underlying syntax highlighting.
+fn highlight_line_matches(
+    line: &Line<'static>,
+    ranges: &[(usize, usize, bool)],
+    theme: &UiTheme,
+) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut offset = 0usize;
+
+    for span in &line.spans {
+        let text = span.content.to_string();
+        let span_start = offset;
+        let span_end = offset + text.len();
+        offset = span_end;
+
+        let mut cuts = vec![0, text.len()];
+        for &(start, end, _) in ranges {
+            if end > span_start && start < span_end {
+                cuts.push(start.saturating_sub(span_start).min(text.len()));
+                cuts.push(end.saturating_sub(span_start).min(text.len()));
+            }
+        }
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        for window in cuts.windows(2) {
+            let (a, b) = (window[0], window[1]);
+            if a >= b {
+                continue;
+            }
+
+            let absolute_start = span_start + a;
+            let absolute_end = span_start + b;
+            let is_current = ranges
+                .iter()
+                .any(|&(s, e, c)| c && s <= absolute_start && absolute_end <= e);
+            let is_match = ranges
+                .iter()
+                .any(|&(s, e, _)| s <= absolute_start && absolute_end <= e);
+
+            let style = if is_current {
+                span.style
+                    .bg(theme.search_match)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else if is_match {
+                span.style.bg(Color::DarkGray).fg(Color::White)
+            } else {
+                span.style
+            };
+
+            spans.push(Span::styled(text[a..b].to_string(), style));
+        }
+    }
+
+    Line::from(spans)
+}
+
+fn draw_search_bar(frame: &mut Frame, area: Rect, app: &App, theme: &UiTheme) {
     let search_state = app.get_search_state();
 
-    let search_text = if search_state.results.is_empty() && !search_state.query.is_empty() {
-        format!("/{} (no matches)", search_state.query)
+    let mut mode_indicators = format!(
+        "[{}]",
+        if search_state.regex_mode { ".*" } else { "text" },
+    );
+    if search_state.case_sensitive {
+        mode_indicators.push_str(" [aA]");
+    }
+    if search_state.whole_word {
+        mode_indicators.push_str(" [\\b]");
+    }
+
+    let search_text = if search_state.invalid_pattern {
+        format!("/{} {} (invalid pattern)", search_state.query, mode_indicators)
+    } else if search_state.results.is_empty() && !search_state.query.is_empty() {
+        format!("/{} {} (no matches)", search_state.query, mode_indicators)
     } else if let Some(current_index) = search_state.current_result_index {
         format!(
-            "/{} ({}/{})",
+            "/{} {} ({}/{})",
             search_state.query,
+            mode_indicators,
             current_index + 1,
             search_state.results.len()
         )
     } else {
-        format!("/{}", search_state.query)
+        format!("/{} {}", search_state.query, mode_indicators)
     };
 
-    let search_color = if search_state.results.is_empty() && !search_state.query.is_empty() {
-        Color::Red
+    let search_color = if search_state.invalid_pattern
+        || (search_state.results.is_empty() && !search_state.query.is_empty())
+    {
+        theme.search_nomatch
     } else {
-        Color::Yellow
+        theme.search_match
     };
 
     let search_bar = Paragraph::new(Line::from(vec![Span::styled(
@@ -117,13 +267,208 @@ fn draw_search_bar(frame: &mut Frame, area: Rect, app: &App) {
         Block::default()
             .borders(Borders::ALL)
             .title("Search")
-            .title_style(Style::default().fg(Color::Cyan)),
+            .title_style(Style::default().fg(theme.header_title)),
     );
 
     frame.render_widget(search_bar, area);
 }
 
-fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
+fn draw_mark_jump_bar(frame: &mut Frame, area: Rect, title: &str, help_text: &str) {
+    let bar = Paragraph::new(Line::from(vec![Span::styled(
+        help_text,
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title.to_string())
+            .title_style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(bar, area);
+}
+
+/// Show the URL of the link currently selected while cycling through on-screen links.
+fn draw_link_bar(frame: &mut Frame, area: Rect, app: &App) {
+    let help_text = match app.get_current_link() {
+        Some(link) => format!("'l' to cycle, Enter to follow, Esc to cancel -> {}", link.url),
+        None => "No links on screen".to_string(),
+    };
+
+    let bar = Paragraph::new(Line::from(vec![Span::styled(
+        help_text,
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Link")
+            .title_style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(bar, area);
+}
+
+/// Render a centered overlay listing the document's headings, with the selected one highlighted.
+fn draw_outline(frame: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(60, 70, area);
+
+    let items: Vec<ListItem> = app
+        .get_outline()
+        .iter()
+        .map(|heading| {
+            let indent = "  ".repeat((heading.level.saturating_sub(1)) as usize);
+            ListItem::new(format!("{}{}", indent, heading.text))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Outline")
+                .title_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut state = ListState::default();
+    state.select(Some(app.get_outline_index()));
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_stateful_widget(list, popup_area, &mut state);
+}
+
+/// Render the always-visible outline sidebar toggled by `Action::ToggleOutlinePane`: the heading
+/// nearest the current scroll position is highlighted, or the in-progress selection while
+/// `AppMode::Outline` is active.
+fn draw_outline_pane(frame: &mut Frame, area: Rect, app: &App, theme: &UiTheme) {
+    let items: Vec<ListItem> = app
+        .get_outline()
+        .iter()
+        .map(|heading| {
+            let indent = "  ".repeat((heading.level.saturating_sub(1)) as usize);
+            ListItem::new(format!("{}{}", indent, heading.text))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Outline")
+                .title_style(Style::default().fg(theme.header_title)),
+        )
+        .style(Style::default().fg(theme.content_fg))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(theme.search_match)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let selected = if *app.get_mode() == AppMode::Outline {
+        Some(app.get_outline_index())
+    } else {
+        app.current_outline_index()
+    };
+
+    let mut state = ListState::default();
+    state.select(selected);
+
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Render a centered overlay listing the built-in UI themes, with the selected one highlighted
+/// and already applied live to the rest of the frame for preview.
+fn draw_theme_picker(frame: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(40, 40, area);
+    let theme = *app.get_ui_theme();
+
+    let items: Vec<ListItem> = theme::ALL
+        .iter()
+        .map(|candidate| ListItem::new(candidate.name))
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Theme")
+                .title_style(Style::default().fg(theme.header_title)),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(theme.search_match)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut state = ListState::default();
+    state.select(Some(app.get_ui_theme_picker_index()));
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_stateful_widget(list, popup_area, &mut state);
+}
+
+/// Render a centered overlay showing reading progress: percent through the document, current
+/// screen out of the total, and the section the reader is currently in.
+fn draw_info(frame: &mut Frame, area: Rect, app: &App) {
+    let popup_area = centered_rect(40, 30, area);
+    let metadata = app.metadata();
+
+    let section = metadata.current_section.as_deref().unwrap_or("(none)");
+    let lines = vec![
+        Line::from(format!("Progress: {:.1}%", metadata.progress_percent)),
+        Line::from(format!(
+            "Screen: {}/{}",
+            metadata.current_screen, metadata.total_screens
+        )),
+        Line::from(format!("Section: {}", section)),
+    ];
+
+    let info = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Info")
+            .title_style(Style::default().fg(Color::Cyan)),
+    );
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(info, popup_area);
+}
+
+/// Carve a `percent_x` by `percent_y` rectangle out of the center of `area`.
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
+fn draw_footer(frame: &mut Frame, area: Rect, app: &App, theme: &UiTheme) {
     let search_state = app.get_search_state();
 
     let help_text = if search_state.is_active && !search_state.results.is_empty() {
@@ -133,20 +478,20 @@ fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
             "Press 'q' to quit | '/' to search | 'n'/'N' for next/prev | 'r' to reload"
         }
     } else if app.is_watching() {
-        "Press 'q' to quit | ↑/↓ or j/k to scroll | '/' to search | Watching for file changes..."
+        "Press 'q' to quit | ↑/↓ or j/k to scroll | '/' to search | 'l' for links | 't' for themes | Watching for file changes..."
     } else {
-        "Press 'q' to quit | ↑/↓ or j/k to scroll | '/' to search | 'r' to reload"
+        "Press 'q' to quit | ↑/↓ or j/k to scroll | '/' to search | 'l' for links | 't' for themes | 'r' to reload"
     };
 
     let footer = Paragraph::new(Line::from(vec![Span::styled(
         help_text,
-        Style::default().fg(Color::Gray),
+        Style::default().fg(theme.help_text),
     )]))
     .block(
         Block::default()
             .borders(Borders::ALL)
             .title("Help")
-            .title_style(Style::default().fg(Color::Green)),
+            .title_style(Style::default().fg(theme.footer_title)),
     );
 
     frame.render_widget(footer, area);